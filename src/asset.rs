@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::asset::{Asset, AssetId, Handle};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+
+/// Asset handle types, which identify an asset of a known type.
+///
+/// Used by adapters such as [`AssetIdOf`] so the asset type can be inferred from the handle. This
+/// trait is sealed, since [`Handle`] is the only type that needs it.
+///
+/// ## Counter Example: Can't be implemented outside this crate
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Asset, TypePath)]
+/// struct Level;
+///
+/// #[derive(Clone)]
+/// struct LevelKey(AssetId<Level>);
+///
+/// impl HandleLike for LevelKey {
+///     type Asset = Level;
+///
+///     fn asset_id(&self) -> AssetId<Level> {
+///         self.0
+///     }
+/// }
+/// ```
+pub trait HandleLike: Clone + sealed::Sealed {
+    type Asset: Asset;
+
+    fn asset_id(&self) -> AssetId<Self::Asset>;
+}
+
+impl<A: Asset> HandleLike for Handle<A> {
+    type Asset = A;
+
+    #[inline]
+    fn asset_id(&self) -> AssetId<A> {
+        self.id()
+    }
+}
+
+mod sealed {
+    use bevy::asset::{Asset, Handle};
+
+    pub trait Sealed {}
+
+    impl<A: Asset> Sealed for Handle<A> {}
+}
+
+#[derive(Debug)]
+pub struct AssetIdOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct HandleClonedQ<T>(PhantomData<T>);
+
+/// Returns the [`AssetId`] of the [`Handle`] a component dereferences to, copied
+///
+/// Useful when ids are used as map keys. Requires the `bevy_asset` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetId;
+/// #[derive(Asset, TypePath)]
+/// struct Level;
+///
+/// #[derive(Component, Deref)]
+/// struct LevelHandle(Handle<Level>);
+///
+/// fn example(query: Query<AssetIdOf<LevelHandle>>) {
+///     let _: AssetId<Level> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let handle = Handle::<Level>::default();
+/// # world.spawn(LevelHandle(handle.clone()));
+/// # let mut query = world.query::<AssetIdOf<LevelHandle>>();
+/// # assert_eq!(query.single(&world), handle.id());
+/// ```
+/// ## Counter Example: Dereferenced type must be a handle
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetId;
+/// #[derive(Asset, TypePath)]
+/// struct Level;
+///
+/// #[derive(Component, Deref)]
+/// struct LevelId(AssetId<Level>);
+///
+/// fn bad_example(query: Query<AssetIdOf<LevelId>>) {
+///     let _: AssetId<Level> = query.get_single().unwrap();
+/// }
+/// ```
+pub type AssetIdOf<T> = ModQ<AssetIdOfQ<T>>;
+impl<T: Component + Deref> ModQuery for AssetIdOfQ<T>
+where
+    <T as Deref>::Target: HandleLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = AssetId<<<T as Deref>::Target as HandleLike>::Asset>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().asset_id()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a clone of the [`Handle`] a component dereferences to
+///
+/// Cloning a strong handle only bumps a reference count, and keeps the asset loaded for as long
+/// as the clone is alive, so it can be used to enqueue work after the query borrow ends.
+/// Requires the `bevy_asset` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Asset, TypePath)]
+/// struct Level;
+///
+/// #[derive(Component, Deref)]
+/// struct LevelHandle(Handle<Level>);
+///
+/// fn example(query: Query<HandleCloned<LevelHandle>>) {
+///     let _: Handle<Level> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let handle = Handle::<Level>::default();
+/// # world.spawn(LevelHandle(handle.clone()));
+/// # let mut query = world.query::<HandleCloned<LevelHandle>>();
+/// # assert_eq!(query.single(&world), handle);
+/// ```
+/// ## Counter Example: Dereferenced type must be a handle
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct LevelName(String);
+///
+/// fn bad_example(query: Query<HandleCloned<LevelName>>) {
+///     let _: String = query.get_single().unwrap();
+/// }
+/// ```
+pub type HandleCloned<T> = ModQ<HandleClonedQ<T>>;
+impl<T: Component + Deref> ModQuery for HandleClonedQ<T>
+where
+    <T as Deref>::Target: HandleLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().clone()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
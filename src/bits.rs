@@ -0,0 +1,162 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+
+/// Primitive integer types of up to 64 bits that can be used as bitfields.
+///
+/// Values are converted to and from `u64` by their bit pattern, without sign extension, so signed
+/// types can be used as bitfields too.
+pub trait Bits: Copy {
+    fn to_bits(self) -> u64;
+    fn from_bits(bits: u64) -> Self;
+}
+
+macro_rules! impl_bits {
+    ($($t:ty => $unsigned:ty),*) => {
+        $(
+            impl Bits for $t {
+                #[inline]
+                fn to_bits(self) -> u64 {
+                    // Through the unsigned type of the same width, so signed values aren't
+                    // sign-extended
+                    self as $unsigned as u64
+                }
+
+                #[inline]
+                fn from_bits(bits: u64) -> Self {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_bits!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+#[derive(Debug)]
+pub struct BitMaskedQ<T, const MASK: u64, const SHIFT: u32>(PhantomData<T>);
+#[derive(Debug)]
+pub struct BitFlagSetQ<T, const MASK: u64>(PhantomData<T>);
+
+/// Returns the dereferenced integer value of a component masked by `MASK`, then shifted right by
+/// `SHIFT` bits (0 by default).
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// // Packed as 0bTTTT_FFFF, where T is a team id and F is a set of flags
+/// #[derive(Component, Deref)]
+/// struct PackedState(u8);
+///
+/// type TeamId = BitMasked<PackedState, 0b1111_0000, 4>;
+/// type Flags = BitMasked<PackedState, 0b0000_1111>;
+///
+/// fn example(query: Query<(TeamId, Flags)>) {
+///     let (_, _): (u8, u8) = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(PackedState(0b0101_0011));
+/// # let mut query = world.query::<(TeamId, Flags)>();
+/// # assert_eq!(query.single(&world), (0b0101, 0b0011));
+/// # #[derive(Component, Deref)]
+/// # struct Signed(i8);
+/// # let id = world.spawn(Signed(-1)).id();
+/// # let mut query = world.query::<(BitMasked<Signed, 0xF0, 4>, BitMasked<Signed, 0xF00, 8>)>();
+/// # assert_eq!(query.get(&world, id).unwrap(), (15, 0));
+/// ```
+/// ## Counter Example: Dereferenced type must be an integer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct PackedState(f32);
+///
+/// fn bad_example(query: Query<BitMasked<PackedState, 0b1111>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type BitMasked<T, const MASK: u64, const SHIFT: u32 = 0> = ModQ<BitMaskedQ<T, MASK, SHIFT>>;
+impl<T: Component + Deref, const MASK: u64, const SHIFT: u32> ModQuery
+    for BitMaskedQ<T, MASK, SHIFT>
+where
+    <T as Deref>::Target: Bits,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        const { assert!(SHIFT < 64, "BitMasked SHIFT must be less than 64") };
+        Bits::from_bits((t.deref().to_bits() & MASK) >> SHIFT)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns `true` if any of the bits in `MASK` are set in the dereferenced integer value of a
+/// component.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct NetFlags(u16);
+///
+/// const DIRTY: u64 = 1 << 3;
+///
+/// fn example(query: Query<BitFlagSet<NetFlags, DIRTY>>) {
+///     for is_dirty in query.iter() {
+///         let _: bool = is_dirty;
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # let dirty = world.spawn(NetFlags(0b1001)).id();
+/// # let clean = world.spawn(NetFlags(0b0001)).id();
+/// # let mut query = world.query::<BitFlagSet<NetFlags, DIRTY>>();
+/// # assert!(query.get(&world, dirty).unwrap());
+/// # assert!(!query.get(&world, clean).unwrap());
+/// # #[derive(Component, Deref)]
+/// # struct Signed(i8);
+/// # let id = world.spawn(Signed(-1)).id();
+/// # let mut query = world.query::<(BitFlagSet<Signed, 0x80>, BitFlagSet<Signed, 0x100>)>();
+/// # assert_eq!(query.get(&world, id).unwrap(), (true, false));
+/// ```
+/// ## Counter Example: Dereferenced type must be an integer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct NetFlags(bool);
+///
+/// fn bad_example(query: Query<BitFlagSet<NetFlags, 1>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type BitFlagSet<T, const MASK: u64> = ModQ<BitFlagSetQ<T, MASK>>;
+impl<T: Component + Deref, const MASK: u64> ModQuery for BitFlagSetQ<T, MASK>
+where
+    <T as Deref>::Target: Bits,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().to_bits() & MASK != 0
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
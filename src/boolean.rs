@@ -0,0 +1,335 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::archetype::Archetype;
+use bevy::ecs::component::{Component, ComponentId, Components, Tick};
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{FilteredAccess, QueryFilter, ReadOnlyQueryData, WorldQuery};
+use bevy::ecs::storage::{Table, TableRow};
+use bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell;
+use bevy::ecs::world::World;
+use bevy::utils::all_tuples;
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct BoolNotQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct BoolAllQ<T, const MISSING: bool>(PhantomData<T>);
+#[derive(Debug)]
+pub struct BoolAnyQ<T, const MISSING: bool>(PhantomData<T>);
+
+/// Returns the negation of a component that dereferences to a `bool`
+///
+/// This lets type aliases express the intended polarity of a flag, instead of negating it in
+/// every system.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct IsHidden(bool);
+///
+/// type IsShown = BoolNot<IsHidden>;
+///
+/// fn example(query: Query<IsShown>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(IsHidden(true));
+/// # let mut query = world.query::<IsShown>();
+/// # assert!(!query.single(&world));
+/// ```
+/// ## Counter Example: Component must dereference to bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct HiddenCount(u32);
+///
+/// fn bad_example(query: Query<BoolNot<HiddenCount>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type BoolNot<T> = ModQ<BoolNotQ<T>>;
+impl<T: Component + Deref<Target = bool>> ModQuery for BoolNotQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        !*t.deref()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns `true` only if every listed component that dereferences to `bool` is `true`
+///
+/// Components missing from the entity are treated as `MISSING`, which is `false` by default.
+/// Because of this, entities are never excluded from the query for lacking any of the components.
+/// Implemented for tuples of 2 to 8 components.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Enabled(bool);
+///
+/// #[derive(Component, Deref)]
+/// struct Visible(bool);
+///
+/// fn example(query: Query<BoolAll<(Enabled, Visible)>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let both = world.spawn((Enabled(true), Visible(true))).id();
+/// # let one = world.spawn((Enabled(true), Visible(false))).id();
+/// # let missing = world.spawn(Enabled(true)).id();
+/// # let mut query = world.query::<BoolAll<(Enabled, Visible)>>();
+/// # assert!(query.get(&world, both).unwrap());
+/// # assert!(!query.get(&world, one).unwrap());
+/// # assert!(!query.get(&world, missing).unwrap());
+/// # let mut query = world.query::<BoolAll<(Enabled, Visible), true>>();
+/// # assert!(query.get(&world, missing).unwrap());
+/// ```
+/// ## Counter Example: Components must dereference to bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Enabled(bool);
+///
+/// #[derive(Component, Deref)]
+/// struct VisibleCount(u32);
+///
+/// fn bad_example(query: Query<BoolAll<(Enabled, VisibleCount)>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type BoolAll<T, const MISSING: bool = false> = ModQ<BoolAllQ<T, MISSING>>;
+
+macro_rules! impl_bool_all {
+    ($($T:ident),*) => {
+        impl<$($T: Component + Deref<Target = bool>),*, const MISSING: bool> ModQuery for BoolAllQ<($($T,)*), MISSING> {
+            type FromQuery = ($(Option<&'static $T>,)*);
+            type ModItem<'a> = bool;
+
+            #[allow(non_snake_case)]
+            fn modify_reference(($($T,)*): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+                $($T.map_or(MISSING, |t| *t.deref()))&&*
+            }
+
+            fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+                item
+            }
+        }
+    };
+}
+
+all_tuples!(impl_bool_all, 2, 8, T);
+
+/// Returns `true` if any of the listed components that dereference to `bool` is `true`
+///
+/// Components missing from the entity are treated as `MISSING`, which is `false` by default.
+/// Because of this, entities are never excluded from the query for lacking any of the components.
+/// Implemented for tuples of 2 to 8 components. See also [`BoolAll`].
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Stunned(bool);
+///
+/// #[derive(Component, Deref)]
+/// struct Frozen(bool);
+///
+/// fn example(query: Query<BoolAny<(Stunned, Frozen)>>) {
+///     let _is_incapacitated: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let one = world.spawn((Stunned(false), Frozen(true))).id();
+/// # let neither = world.spawn((Stunned(false), Frozen(false))).id();
+/// # let missing = world.spawn(Stunned(false)).id();
+/// # let mut query = world.query::<BoolAny<(Stunned, Frozen)>>();
+/// # assert!(query.get(&world, one).unwrap());
+/// # assert!(!query.get(&world, neither).unwrap());
+/// # assert!(!query.get(&world, missing).unwrap());
+/// # let mut query = world.query::<BoolAny<(Stunned, Frozen), true>>();
+/// # assert!(query.get(&world, missing).unwrap());
+/// ```
+/// ## Counter Example: Components must dereference to bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Stunned(bool);
+///
+/// #[derive(Component, Deref)]
+/// struct FrozenTurns(u32);
+///
+/// fn bad_example(query: Query<BoolAny<(Stunned, FrozenTurns)>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type BoolAny<T, const MISSING: bool = false> = ModQ<BoolAnyQ<T, MISSING>>;
+
+macro_rules! impl_bool_any {
+    ($($T:ident),*) => {
+        impl<$($T: Component + Deref<Target = bool>),*, const MISSING: bool> ModQuery for BoolAnyQ<($($T,)*), MISSING> {
+            type FromQuery = ($(Option<&'static $T>,)*);
+            type ModItem<'a> = bool;
+
+            #[allow(non_snake_case)]
+            fn modify_reference(($($T,)*): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+                $($T.map_or(MISSING, |t| *t.deref()))||*
+            }
+
+            fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+                item
+            }
+        }
+    };
+}
+
+all_tuples!(impl_bool_any, 2, 8, T);
+
+/// A query filter that only matches entities where the `bool` query data `D` is `true`
+///
+/// `D` can be any read-only query data that yields a `bool`, such as a flag adapter, so the same
+/// type can be used as data in one system and as a filter in another. Entities that don't match
+/// `D` itself, such as ones missing the component it reads, are never matched.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct IsHidden(bool);
+///
+/// fn example(query: Query<Entity, AsFilter<BoolNot<IsHidden>>>) {
+///     for _shown in query.iter() {}
+/// }
+///
+/// # let mut world = World::new();
+/// # let shown = world.spawn(IsHidden(false)).id();
+/// # world.spawn(IsHidden(true));
+/// # world.spawn_empty();
+/// # let mut query = world.query_filtered::<Entity, AsFilter<BoolNot<IsHidden>>>();
+/// # assert_eq!(query.iter(&world).collect::<Vec<_>>(), [shown]);
+/// ```
+/// ## Counter Example: Query data must be a bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct HiddenCount(u32);
+///
+/// fn bad_example(query: Query<Entity, AsFilter<AsDerefCopied<HiddenCount>>>) {
+///     for _shown in query.iter() {}
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsFilter<D>(PhantomData<D>);
+
+// SAFETY: All access is delegated to `D`.
+unsafe impl<D> WorldQuery for AsFilter<D>
+where
+    D: ReadOnlyQueryData + for<'w> WorldQuery<Item<'w> = bool>,
+{
+    type Fetch<'w> = <D as WorldQuery>::Fetch<'w>;
+    type Item<'w> = bool;
+    type State = <D as WorldQuery>::State;
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    const IS_DENSE: bool = D::IS_DENSE;
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        // SAFETY: Upheld by the caller.
+        unsafe { D::init_fetch(world, state, last_run, this_run) }
+    }
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: Upheld by the caller.
+        unsafe { D::set_archetype(fetch, state, archetype, table) }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: Upheld by the caller.
+        unsafe { D::set_table(fetch, state, table) }
+    }
+
+    #[inline]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: Upheld by the caller.
+        unsafe { D::fetch(fetch, entity, table_row) }
+    }
+
+    fn shrink_fetch<'wlong: 'wshort, 'wshort>(fetch: Self::Fetch<'wlong>) -> Self::Fetch<'wshort> {
+        D::shrink_fetch(fetch)
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        D::update_component_access(state, access)
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        D::init_state(world)
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        D::get_state(components)
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        D::matches_component_set(state, set_contains_id)
+    }
+}
+
+// SAFETY: `filter_fetch` only fetches `D`, which is read-only.
+unsafe impl<D> QueryFilter for AsFilter<D>
+where
+    D: ReadOnlyQueryData + for<'w> WorldQuery<Item<'w> = bool>,
+{
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: Upheld by the caller.
+        unsafe { D::fetch(fetch, entity, table_row) }
+    }
+}
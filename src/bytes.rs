@@ -0,0 +1,108 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bytemuck::Pod;
+
+use super::base::{ModQ, ModQuery};
+use super::extensions::AsDeref;
+
+#[derive(Debug)]
+pub struct AsBytesQ<T>(PhantomData<T>);
+
+/// Returns the bytes of a [`Pod`] component as `&[u8]`, without copying
+///
+/// Requires the `bytemuck` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Copy)]
+/// #[repr(C)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// unsafe impl bytemuck::Zeroable for Velocity {}
+/// unsafe impl bytemuck::Pod for Velocity {}
+///
+/// fn example(query: Query<AsBytes<Velocity>>) {
+///     let _: &[u8] = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Velocity { x: 1.0, y: -2.0 });
+/// # let mut query = world.query::<AsBytes<Velocity>>();
+/// # let bytes = query.single(&world);
+/// # assert_eq!(bytes.len(), 8);
+/// # assert_eq!(&bytes[..4], &1.0f32.to_ne_bytes());
+/// ```
+/// ## Counter Example: Component must be `Pod`
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Copy)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// fn bad_example(query: Query<AsBytes<Velocity>>) {
+///     let _: &[u8] = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsBytes<T> = ModQ<AsBytesQ<T>>;
+
+/// Returns the bytes of the [`Pod`] value a component dereferences to as `&[u8]`, without copying
+///
+/// Requires the `bytemuck` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Palette([u8; 4]);
+///
+/// fn example(query: Query<AsDerefAsBytes<Palette>>) {
+///     let _: &[u8] = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Palette([1, 2, 3, 4]));
+/// # let mut query = world.query::<AsDerefAsBytes<Palette>>();
+/// # assert_eq!(query.single(&world), &[1, 2, 3, 4]);
+/// ```
+pub type AsDerefAsBytes<T> = AsBytes<AsDeref<T>>;
+
+impl<T: Component + Pod> ModQuery for AsBytesQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a [u8];
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        bytemuck::bytes_of(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+impl<T: Component + Deref> ModQuery for AsBytesQ<AsDeref<T>>
+where
+    <T as Deref>::Target: Pod,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a [u8];
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        bytemuck::bytes_of(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use bevy::app::{App, Plugin};
+use bevy::ecs::change_detection::{DetectChanges, Ref};
+use bevy::ecs::component::{Component, Tick};
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+
+/// A plugin that lets [`CachedCloned<T>`] reuse clones of component `T` until it changes
+///
+/// The clones are stored in a [`ClonedCache<T>`] component, which is inserted and removed along
+/// with `T` using commands. The cache is kept per entity, rather than in a single resource, so
+/// that each entity's clone is dropped along with the entity and caches for different entities
+/// never share a lock.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Inventory(Vec<String>);
+///
+/// App::new().add_plugins(CachedClonedPlugin::<Inventory>::default());
+/// ```
+pub struct CachedClonedPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for CachedClonedPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Debug for CachedClonedPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CachedClonedPlugin").finish()
+    }
+}
+
+impl<T: Component + Clone> Plugin for CachedClonedPlugin<T> {
+    fn build(&self, app: &mut App) {
+        add_companion::<T, ClonedCache<T>>(app);
+    }
+}
+
+/// The last clone of component `T` that [`CachedCloned<T>`] returned, and when `T` had last
+/// changed at the time, kept by [`CachedClonedPlugin<T>`]
+///
+/// The clone is behind a [`Mutex`], so that the read-only `CachedCloned<T>` can update it. Every
+/// read of an entity through `CachedCloned<T>` locks its cache briefly, so systems reading the
+/// same entity in parallel wait on each other for that lock. The lock is only held to compare the
+/// change tick and clone the [`Arc`], or to clone `T` after it has changed.
+#[derive(Component)]
+pub struct ClonedCache<T: Component + Clone>(Mutex<Option<(Tick, Arc<T>)>>);
+
+impl<T: Component + Clone> Debug for ClonedCache<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ClonedCache").finish_non_exhaustive()
+    }
+}
+
+impl<T: Component + Clone> Companion<T> for ClonedCache<T> {
+    fn new(_: &T) -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+#[derive(Debug)]
+pub struct CachedClonedQ<T>(PhantomData<T>);
+
+/// Returns a shared clone of component `T`, only cloning it again after it has changed
+///
+/// Useful for handing big components to other threads every frame. Requires
+/// [`CachedClonedPlugin<T>`] to be added to the app.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::Arc;
+/// #[derive(Component, Clone)]
+/// struct Inventory(Vec<String>);
+///
+/// fn example(query: Query<CachedCloned<Inventory>>) {
+///     let _: Arc<Inventory> = query.get_single().unwrap();
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(CachedClonedPlugin::<Inventory>::default());
+/// # let entity = app.world_mut().spawn(Inventory(vec!["Sword".to_string()])).id();
+/// # app.update();
+/// # let mut query = app.world_mut().query::<CachedCloned<Inventory>>();
+/// # let first = query.single(app.world());
+/// # assert!(Arc::ptr_eq(&first, &query.single(app.world())));
+/// # app.world_mut().get_mut::<Inventory>(entity).unwrap().0.push("Shield".to_string());
+/// # let second = query.single(app.world());
+/// # assert!(!Arc::ptr_eq(&first, &second));
+/// # assert_eq!(second.0.len(), 2);
+/// # assert!(Arc::ptr_eq(&second, &query.single(app.world())));
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::Arc;
+/// #[derive(Component)]
+/// struct Inventory(Vec<String>);
+///
+/// fn bad_example(query: Query<CachedCloned<Inventory>>) {
+///     let _: Arc<Inventory> = query.get_single().unwrap();
+/// }
+/// ```
+pub type CachedCloned<T> = ModQ<CachedClonedQ<T>>;
+impl<T: Component + Clone> ModQuery for CachedClonedQ<T> {
+    type FromQuery = (Ref<'static, T>, &'static ClonedCache<T>);
+    type ModItem<'a> = Arc<T>;
+
+    fn modify_reference(
+        (value, cache): <Self::FromQuery as WorldQuery>::Item<'_>,
+    ) -> Self::ModItem<'_> {
+        let mut cache = cache.0.lock().unwrap_or_else(PoisonError::into_inner);
+        match &*cache {
+            Some((changed, cached)) if *changed == value.last_changed() => cached.clone(),
+            _ => {
+                let cloned = Arc::new(value.clone());
+                *cache = Some((value.last_changed(), cloned.clone()));
+                cloned
+            }
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::ecs::query::WorldQuery;
+use bevy::render::camera::Camera;
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct CameraIsActiveFlagQ;
+
+/// Returns whether a [`Camera`] is active, i.e. whether it renders
+///
+/// Use [`AsFilter<CameraIsActiveFlag>`](crate::AsFilter) to only iterate active cameras. Requires
+/// the `bevy_render` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<(Entity, CameraIsActiveFlag)>) {
+///     for (camera, is_active) in query.iter() {
+///         let _: (Entity, bool) = (camera, is_active);
+///     }
+/// }
+///
+/// fn active_cameras(query: Query<Entity, AsFilter<CameraIsActiveFlag>>) {
+///     for _camera in query.iter() {}
+/// }
+///
+/// # let mut world = World::new();
+/// # let inactive = world.spawn(Camera {
+/// #     is_active: false,
+/// #     ..default()
+/// # }).id();
+/// # let active = world.spawn(Camera::default()).id();
+/// # let mut query = world.query::<CameraIsActiveFlag>();
+/// # assert!(query.get(&world, active).unwrap());
+/// # assert!(!query.get(&world, inactive).unwrap());
+/// # let mut query = world.query_filtered::<Entity, AsFilter<CameraIsActiveFlag>>();
+/// # assert_eq!(query.iter(&world).collect::<Vec<_>>(), [active]);
+/// ```
+/// ## Counter Example: CameraIsActiveFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, CameraIsActiveFlag>) {
+///     for _camera in query.iter() {}
+/// }
+/// ```
+pub type CameraIsActiveFlag = ModQ<CameraIsActiveFlagQ>;
+impl ModQuery for CameraIsActiveFlagQ {
+    type FromQuery = &'static Camera;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.is_active
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,750 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::{BuildHasher, Hash};
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::utils::hashbrown;
+
+use super::base::{ModQ, ModQuery};
+use super::provider::Provider;
+
+/// Types that store their elements contiguously and can be viewed as a slice.
+///
+/// Used by adapters such as [`AsSlice`] so they work the same on `Vec`s, arrays, boxed slices and
+/// slices.
+pub trait SliceLike {
+    type Element;
+
+    fn as_slice(&self) -> &[Self::Element];
+}
+
+impl<U> SliceLike for [U] {
+    type Element = U;
+
+    #[inline]
+    fn as_slice(&self) -> &[U] {
+        self
+    }
+}
+
+impl<U, const N: usize> SliceLike for [U; N] {
+    type Element = U;
+
+    #[inline]
+    fn as_slice(&self) -> &[U] {
+        self
+    }
+}
+
+impl<U> SliceLike for Vec<U> {
+    type Element = U;
+
+    #[inline]
+    fn as_slice(&self) -> &[U] {
+        self
+    }
+}
+
+impl<U> SliceLike for Box<[U]> {
+    type Element = U;
+
+    #[inline]
+    fn as_slice(&self) -> &[U] {
+        self
+    }
+}
+
+/// Collection types that know how many elements they contain.
+///
+/// Used by adapters such as [`LenOf`] so they work the same across slices, `Vec`s, maps, sets
+/// and strings. For strings, the length is in bytes.
+pub trait Collection {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! impl_collection {
+    ($(impl<$($g:ident),*> for $t:ty;)*) => {
+        $(
+            impl<$($g),*> Collection for $t {
+                #[inline]
+                fn len(&self) -> usize {
+                    <$t>::len(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_collection! {
+    impl<U> for [U];
+    impl<> for str;
+}
+
+impl_collection! {
+    impl<U> for Vec<U>;
+    impl<U> for VecDeque<U>;
+    impl<K, V, S> for HashMap<K, V, S>;
+    impl<K, S> for HashSet<K, S>;
+    impl<K, V, S> for hashbrown::HashMap<K, V, S>;
+    impl<K, S> for hashbrown::HashSet<K, S>;
+    impl<K, V> for BTreeMap<K, V>;
+    impl<K> for BTreeSet<K>;
+    impl<> for String;
+}
+
+impl<U> Collection for Box<[U]> {
+    #[inline]
+    fn len(&self) -> usize {
+        <[U]>::len(self)
+    }
+}
+
+impl<U, const N: usize> Collection for [U; N] {
+    #[inline]
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+/// Map types that can look up a value by key.
+///
+/// Used by [`KeyedGet`] so it works the same across `HashMap`s and `BTreeMap`s.
+pub trait MapLike {
+    type Key;
+    type Value;
+
+    fn get_value(&self, key: &Self::Key) -> Option<&Self::Value>;
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapLike for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get_value(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> MapLike for hashbrown::HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get_value(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+impl<K: Ord, V> MapLike for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get_value(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+}
+
+#[derive(Debug)]
+pub struct AsSliceQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct LenOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct IsEmptyOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct FirstOfSliceQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct LastOfSliceQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct GetIndexQ<T, const I: usize>(PhantomData<T>);
+#[derive(Debug)]
+pub struct SumOfElementsQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct MaxElementOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct MinElementOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct CountOfIterQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct KeyedGetQ<T, P>(PhantomData<(T, P)>);
+
+/// Finds the element that is the furthest in the direction of `ordering`, skipping elements that
+/// can't be compared with themselves (i.e. NaN) unless every element is like that.
+fn extreme_element<U: Copy + PartialOrd>(elements: &[U], ordering: Ordering) -> Option<U> {
+    elements
+        .iter()
+        .copied()
+        .reduce(|extreme, element| match element.partial_cmp(&extreme) {
+            Some(cmp) if cmp == ordering => element,
+            None if extreme.partial_cmp(&extreme).is_none() => element,
+            _ => extreme,
+        })
+}
+
+/// Returns a slice of the elements of a component that dereferences to a `Vec`, array or slice
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Waypoints(Vec<Vec2>);
+///
+/// fn example(query: Query<AsSlice<Waypoints>>) {
+///     let _: &[Vec2] = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced type must be contiguous
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::collections::VecDeque;
+/// #[derive(Component, Deref)]
+/// struct Waypoints(VecDeque<Vec2>);
+///
+/// fn bad_example(query: Query<AsSlice<Waypoints>>) {
+///     let _: &[Vec2] = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsSlice<T> = ModQ<AsSliceQ<T>>;
+impl<T: Component + Deref> ModQuery for AsSliceQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a [<<T as Deref>::Target as SliceLike>::Element];
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_slice()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the number of elements in a component that dereferences to a collection, such as a
+/// `Vec`, slice, map, set or `String` (length in bytes)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// #[derive(Component, Deref)]
+/// struct StatusEffects(Vec<Entity>);
+///
+/// #[derive(Component, Deref)]
+/// struct Cooldowns(HashMap<String, f32>);
+///
+/// fn example(query: Query<(LenOf<StatusEffects>, LenOf<Cooldowns>)>) {
+///     let (_, _): (usize, usize) = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced type must be a collection
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct StatusEffect(Entity);
+///
+/// fn bad_example(query: Query<LenOf<StatusEffect>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+/// ```
+pub type LenOf<T> = ModQ<LenOfQ<T>>;
+impl<T: Component + Deref> ModQuery for LenOfQ<T>
+where
+    <T as Deref>::Target: Collection,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Collection::len(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns `true` if a component that dereferences to a collection has no elements
+///
+/// Unlike a filter, every entity with the component is still visited, so systems can branch on
+/// this per entity.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct CommandQueue(Vec<String>);
+///
+/// fn example(query: Query<IsEmptyOf<CommandQueue>>) {
+///     let _is_idle: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let empty = world.spawn(CommandQueue(vec![])).id();
+/// # let busy = world.spawn(CommandQueue(vec!["move".to_string()])).id();
+/// # let mut query = world.query::<IsEmptyOf<CommandQueue>>();
+/// # assert!(query.get(&world, empty).unwrap());
+/// # assert!(!query.get(&world, busy).unwrap());
+/// ```
+/// ## Counter Example: Dereferenced type must be a collection
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct NextCommand(Option<String>);
+///
+/// fn bad_example(query: Query<IsEmptyOf<NextCommand>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type IsEmptyOf<T> = ModQ<IsEmptyOfQ<T>>;
+impl<T: Component + Deref> ModQuery for IsEmptyOfQ<T>
+where
+    <T as Deref>::Target: Collection,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Collection::is_empty(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the first element of a component that dereferences to a `Vec`, array
+/// or slice, or `None` if it is empty
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Path(Vec<Vec2>);
+///
+/// fn example(query: Query<FirstOfSlice<Path>>) {
+///     let _next_waypoint: Option<&Vec2> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Path(vec![Vec2::X, Vec2::Y]));
+/// # let mut query = world.query::<FirstOfSlice<Path>>();
+/// # assert_eq!(query.single(&world), Some(&Vec2::X));
+/// ```
+/// ## Counter Example: Dereferenced type must be contiguous
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::collections::VecDeque;
+/// #[derive(Component, Deref)]
+/// struct Path(VecDeque<Vec2>);
+///
+/// fn bad_example(query: Query<FirstOfSlice<Path>>) {
+///     let _: Option<&Vec2> = query.get_single().unwrap();
+/// }
+/// ```
+pub type FirstOfSlice<T> = ModQ<FirstOfSliceQ<T>>;
+impl<T: Component + Deref> ModQuery for FirstOfSliceQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<&'a <<T as Deref>::Target as SliceLike>::Element>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_slice().first()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the last element of a component that dereferences to a `Vec`, array
+/// or slice, or `None` if it is empty
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct UndoStack(Vec<String>);
+///
+/// fn example(query: Query<LastOfSlice<UndoStack>>) {
+///     let _top: Option<&String> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(UndoStack(vec!["a".to_string(), "b".to_string()]));
+/// # let mut query = world.query::<LastOfSlice<UndoStack>>();
+/// # assert_eq!(query.single(&world).map(String::as_str), Some("b"));
+/// ```
+pub type LastOfSlice<T> = ModQ<LastOfSliceQ<T>>;
+impl<T: Component + Deref> ModQuery for LastOfSliceQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<&'a <<T as Deref>::Target as SliceLike>::Element>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_slice().last()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the element at index `I` of a component that dereferences to a `Vec`,
+/// array or slice, or `None` if it is out of bounds
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct EquipmentSlots([Option<Entity>; 4]);
+///
+/// type MainHand = GetIndex<EquipmentSlots, 0>;
+/// type OffHand = GetIndex<EquipmentSlots, 1>;
+///
+/// fn example(query: Query<(MainHand, OffHand)>) {
+///     let (_, _): (Option<&Option<Entity>>, Option<&Option<Entity>>) =
+///         query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(EquipmentSlots([Some(Entity::PLACEHOLDER), None, None, None]));
+/// # let mut query = world.query::<(MainHand, OffHand, GetIndex<EquipmentSlots, 4>)>();
+/// # assert_eq!(query.single(&world), (Some(&Some(Entity::PLACEHOLDER)), Some(&None), None));
+/// ```
+/// ## Counter Example: Dereferenced type must be contiguous
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::collections::VecDeque;
+/// #[derive(Component, Deref)]
+/// struct EquipmentSlots(VecDeque<Entity>);
+///
+/// fn bad_example(query: Query<GetIndex<EquipmentSlots, 0>>) {
+///     let _: Option<&Entity> = query.get_single().unwrap();
+/// }
+/// ```
+pub type GetIndex<T, const I: usize> = ModQ<GetIndexQ<T, I>>;
+impl<T: Component + Deref, const I: usize> ModQuery for GetIndexQ<T, I>
+where
+    <T as Deref>::Target: SliceLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<&'a <<T as Deref>::Target as SliceLike>::Element>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_slice().get(I)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the sum of all the elements of a component that dereferences to a `Vec`, array or
+/// slice of numbers
+///
+/// An empty collection sums to zero. As with [`Iterator::sum`], integer overflow panics in debug
+/// builds.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct DamageStack(Vec<f32>);
+///
+/// fn example(query: Query<SumOfElements<DamageStack>>) {
+///     let _total_damage: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(DamageStack(vec![1.5, 2.0, 0.5]));
+/// # let mut query = world.query::<SumOfElements<DamageStack>>();
+/// # assert_eq!(query.single(&world), 4.0);
+/// ```
+/// ## Counter Example: Elements must be summable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct DamageStack(Vec<String>);
+///
+/// fn bad_example(query: Query<SumOfElements<DamageStack>>) {
+///     let _: String = query.get_single().unwrap();
+/// }
+/// ```
+pub type SumOfElements<T> = ModQ<SumOfElementsQ<T>>;
+impl<T: Component + Deref> ModQuery for SumOfElementsQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+    <<T as Deref>::Target as SliceLike>::Element: Copy + Sum,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <<T as Deref>::Target as SliceLike>::Element;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_slice().iter().copied().sum()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a copy of the largest element of a component that dereferences to a `Vec`, array or
+/// slice, or `None` if it is empty
+///
+/// Elements only need to be `PartialOrd`, so this works for floats: NaN elements are ignored
+/// unless every element is NaN. If several elements are equally large, the first is returned.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Bids(Vec<u32>);
+///
+/// fn example(query: Query<MaxElementOf<Bids>>) {
+///     let _highest_bid: Option<u32> = query.get_single().unwrap();
+/// }
+///
+/// # #[derive(Component, Deref)]
+/// # struct Readings(Vec<f32>);
+/// # let mut world = World::new();
+/// # let a = world.spawn(Readings(vec![1.0, f32::NAN, 3.0, 2.0])).id();
+/// # let b = world.spawn(Readings(vec![])).id();
+/// # let mut query = world.query::<MaxElementOf<Readings>>();
+/// # assert_eq!(query.get(&world, a).unwrap(), Some(3.0));
+/// # assert_eq!(query.get(&world, b).unwrap(), None);
+/// ```
+/// ## Counter Example: Elements must be comparable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Waypoints(Vec<Vec2>);
+///
+/// fn bad_example(query: Query<MaxElementOf<Waypoints>>) {
+///     let _: Option<Vec2> = query.get_single().unwrap();
+/// }
+/// ```
+pub type MaxElementOf<T> = ModQ<MaxElementOfQ<T>>;
+impl<T: Component + Deref> ModQuery for MaxElementOfQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+    <<T as Deref>::Target as SliceLike>::Element: Copy + PartialOrd,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<<<T as Deref>::Target as SliceLike>::Element>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        extreme_element(t.deref().as_slice(), Ordering::Greater)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a copy of the smallest element of a component that dereferences to a `Vec`, array or
+/// slice, or `None` if it is empty
+///
+/// Elements only need to be `PartialOrd`, so this works for floats: NaN elements are ignored
+/// unless every element is NaN. If several elements are equally small, the first is returned.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct LapTimes(Vec<f32>);
+///
+/// fn example(query: Query<MinElementOf<LapTimes>>) {
+///     let _best_lap: Option<f32> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let a = world.spawn(LapTimes(vec![f32::NAN, 61.5, 59.25, 60.0])).id();
+/// # let b = world.spawn(LapTimes(vec![f32::NAN])).id();
+/// # let mut query = world.query::<MinElementOf<LapTimes>>();
+/// # assert_eq!(query.get(&world, a).unwrap(), Some(59.25));
+/// # assert!(query.get(&world, b).unwrap().unwrap().is_nan());
+/// ```
+pub type MinElementOf<T> = ModQ<MinElementOfQ<T>>;
+impl<T: Component + Deref> ModQuery for MinElementOfQ<T>
+where
+    <T as Deref>::Target: SliceLike,
+    <<T as Deref>::Target as SliceLike>::Element: Copy + PartialOrd,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<<<T as Deref>::Target as SliceLike>::Element>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        extreme_element(t.deref().as_slice(), Ordering::Less)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the number of elements yielded by iterating over a reference to a component
+///
+/// This works for any component where `&T: IntoIterator`, such as custom collection types that
+/// don't dereference to a standard collection. Unlike [`LenOf`], this has to walk the whole
+/// iterator, so prefer [`LenOf`] when the component supports it.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Party {
+///     leader: Entity,
+///     members: Vec<Entity>,
+/// }
+///
+/// impl<'a> IntoIterator for &'a Party {
+///     type Item = &'a Entity;
+///     type IntoIter = std::iter::Chain<std::iter::Once<&'a Entity>, std::slice::Iter<'a, Entity>>;
+///
+///     fn into_iter(self) -> Self::IntoIter {
+///         std::iter::once(&self.leader).chain(self.members.iter())
+///     }
+/// }
+///
+/// fn example(query: Query<CountOfIter<Party>>) {
+///     let _party_size: usize = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Party { leader: Entity::PLACEHOLDER, members: vec![Entity::PLACEHOLDER] });
+/// # let mut query = world.query::<CountOfIter<Party>>();
+/// # assert_eq!(query.single(&world), 2);
+/// ```
+/// ## Counter Example: Reference to component must implement IntoIterator
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Party {
+///     leader: Entity,
+///     members: Vec<Entity>,
+/// }
+///
+/// fn bad_example(query: Query<CountOfIter<Party>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+/// ```
+pub type CountOfIter<T> = ModQ<CountOfIterQ<T>>;
+impl<T: Component> ModQuery for CountOfIterQ<T>
+where
+    for<'a> &'a T: IntoIterator,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.into_iter().count()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the value stored under the key provided by `P` in a component that
+/// dereferences to a `HashMap` or `BTreeMap`, or `None` if there is no such entry
+///
+/// Since the key is provided as a constant, the map's key type must be constructible in a
+/// constant, such as integers, enums or `&'static str`.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Ability {
+///     Fireball,
+///     Dash,
+/// }
+///
+/// #[derive(Component, Deref)]
+/// struct Cooldowns(HashMap<Ability, f32>);
+///
+/// struct DashKey;
+///
+/// impl Provider<Ability> for DashKey {
+///     const VALUE: Ability = Ability::Dash;
+/// }
+///
+/// fn example(query: Query<KeyedGet<Cooldowns, DashKey>>) {
+///     let _dash_cooldown: Option<&f32> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Cooldowns(HashMap::from_iter([(Ability::Dash, 1.5)])));
+/// # let mut query = world.query::<KeyedGet<Cooldowns, DashKey>>();
+/// # assert_eq!(query.single(&world), Some(&1.5));
+/// ```
+/// ## Counter Example: Provided key must be the map's key type
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::utils::HashMap;
+/// #[derive(Component, Deref)]
+/// struct Cooldowns(HashMap<u32, f32>);
+///
+/// struct DashKey;
+///
+/// impl Provider<&'static str> for DashKey {
+///     const VALUE: &'static str = "dash";
+/// }
+///
+/// fn bad_example(query: Query<KeyedGet<Cooldowns, DashKey>>) {
+///     let _: Option<&f32> = query.get_single().unwrap();
+/// }
+/// ```
+pub type KeyedGet<T, P> = ModQ<KeyedGetQ<T, P>>;
+impl<T: Component + Deref, P> ModQuery for KeyedGetQ<T, P>
+where
+    <T as Deref>::Target: MapLike,
+    P: Provider<<<T as Deref>::Target as MapLike>::Key>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<&'a <<T as Deref>::Target as MapLike>::Value>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().get_value(&P::VALUE)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
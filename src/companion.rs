@@ -0,0 +1,33 @@
+use bevy::app::App;
+use bevy::ecs::component::Component;
+use bevy::ecs::observer::Trigger;
+use bevy::ecs::system::{Commands, Query};
+use bevy::ecs::world::{OnAdd, OnRemove};
+
+/// A component that plugins keep alongside component `T`, like [`Previous<T>`](crate::Previous).
+pub(crate) trait Companion<T>: Component {
+    fn new(value: &T) -> Self;
+}
+
+/// Adds observers that insert `C` when `T` is added and remove it when `T` is removed.
+pub(crate) fn add_companion<T: Component, C: Companion<T>>(app: &mut App) -> &mut App {
+    app.add_observer(insert_companion::<T, C>)
+        .add_observer(remove_companion::<T, C>)
+}
+
+fn insert_companion<T: Component, C: Companion<T>>(
+    trigger: Trigger<OnAdd, T>,
+    query: Query<&T>,
+    mut commands: Commands,
+) {
+    if let Ok(value) = query.get(trigger.entity()) {
+        commands.entity(trigger.entity()).try_insert(C::new(value));
+    }
+}
+
+fn remove_companion<T: Component, C: Companion<T>>(
+    trigger: Trigger<OnRemove, T>,
+    mut commands: Commands,
+) {
+    commands.entity(trigger.entity()).remove::<C>();
+}
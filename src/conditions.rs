@@ -0,0 +1,138 @@
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::Changed;
+use bevy::ecs::system::{Query, Resource};
+
+use super::extensions::AsDerefCopied;
+use super::system_params::DerefRes;
+
+/// A run condition that's true if any entity's component dereferences to `true`
+///
+/// Like `Query<AsDerefCopied<T>>` when used in a query.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Deref)]
+/// struct Enabled(bool);
+///
+/// fn example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(any_with_deref_true::<Enabled>));
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Enabled(false));
+/// # assert!(!world.run_system_once(any_with_deref_true::<Enabled>).unwrap());
+/// # world.spawn(Enabled(true));
+/// # assert!(world.run_system_once(any_with_deref_true::<Enabled>).unwrap());
+/// ```
+/// ## Counter Example: Component must deref to bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Enabled(u8);
+///
+/// fn bad_example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(any_with_deref_true::<Enabled>));
+/// }
+/// ```
+pub fn any_with_deref_true<T: Component + Deref<Target = bool>>(
+    query: Query<AsDerefCopied<T>>,
+) -> bool {
+    query.iter().any(|value| value)
+}
+
+/// A run condition that's true if any entity's component has changed since the condition last ran
+/// and dereferences to `true`
+///
+/// Like `Query<AsDerefCopied<T>, Changed<T>>` when used in a query.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref, DerefMut)]
+/// struct Dirty(bool);
+///
+/// fn example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(any_changed_and_true::<Dirty>));
+/// }
+///
+/// # let mut world = World::new();
+/// # let entity = world.spawn(Dirty(true)).id();
+/// # let mut condition = IntoSystem::into_system(any_changed_and_true::<Dirty>);
+/// # condition.initialize(&mut world);
+/// # assert!(condition.run((), &mut world));
+/// # assert!(!condition.run((), &mut world));
+/// # world.entity_mut(entity).get_mut::<Dirty>().unwrap().0 = false;
+/// # assert!(!condition.run((), &mut world));
+/// # world.entity_mut(entity).get_mut::<Dirty>().unwrap().0 = true;
+/// # assert!(condition.run((), &mut world));
+/// ```
+/// ## Counter Example: Component must deref to bool
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Dirty(u8);
+///
+/// fn bad_example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(any_changed_and_true::<Dirty>));
+/// }
+/// ```
+pub fn any_changed_and_true<T: Component + Deref<Target = bool>>(
+    query: Query<AsDerefCopied<T>, Changed<T>>,
+) -> bool {
+    query.iter().any(|value| value)
+}
+
+/// Returns a run condition that's true if a resource dereferences to a value equal to `value`
+///
+/// Like bevy's [`resource_equals`](bevy::prelude::resource_equals), using [`DerefRes`]. The
+/// condition isn't run if the resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// enum GameMode {
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[derive(Resource, Deref)]
+/// struct Mode(GameMode);
+///
+/// fn example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(resource_deref_equals::<Mode>(GameMode::Playing)));
+/// }
+///
+/// # let mut world = World::new();
+/// # world.insert_resource(Mode(GameMode::Menu));
+/// # assert!(!world.run_system_once(resource_deref_equals::<Mode>(GameMode::Playing)).unwrap());
+/// # world.insert_resource(Mode(GameMode::Playing));
+/// # assert!(world.run_system_once(resource_deref_equals::<Mode>(GameMode::Playing)).unwrap());
+/// ```
+/// ## Counter Example: Resource must be Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource, Clone, Copy, PartialEq)]
+/// struct Mode(u8);
+///
+/// fn bad_example(schedule: &mut Schedule) {
+///     schedule.add_systems((|| {}).run_if(resource_deref_equals::<Mode>(Mode(1))));
+/// }
+/// ```
+pub fn resource_deref_equals<R>(value: R::Target) -> impl FnMut(DerefRes<R>) -> bool + Clone
+where
+    R: Resource + Deref<Target: PartialEq + Clone + Send + Sync + Sized>,
+{
+    move |res| *res == value
+}
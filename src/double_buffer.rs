@@ -0,0 +1,220 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::app::{App, Last, Plugin};
+use bevy::ecs::change_detection::{DetectChanges, DetectChangesMut, Mut};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::ecs::system::Query;
+
+use super::base::{ModQ, ModQMut, ModQuery, ModQueryMut};
+use super::companion::{add_companion, Companion};
+use super::extensions::AsDeref;
+
+/// A plugin that double buffers component `T`, for use with [`ReadBuffer`] and [`WriteBuffer`]
+///
+/// `T` itself is the front buffer, and the back buffer is a [`BackBuffer<T>`] component, which is
+/// inserted and removed along with `T` using commands. Once a frame, in the schedule the plugin is
+/// created with ([`Last`] by default), the back buffer is copied to the front if it has been
+/// written to. So systems reading with `ReadBuffer<T>` all see the same value, no matter which
+/// systems wrote with `WriteBuffer<T>` before them.
+///
+/// Writing to `T` directly instead of through `WriteBuffer<T>` is not supported. To keep the
+/// back buffer from going stale, a direct write is copied to the back buffer when the buffers are
+/// swapped, but only if the back buffer wasn't written to as well. Otherwise the direct write is
+/// overwritten.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct CellState(bool);
+///
+/// App::new().add_plugins(DoubleBufferedPlugin::<CellState>::new(PostUpdate));
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(DoubleBufferedPlugin::<CellState>::default());
+/// # let entity = app.world_mut().spawn(CellState(false)).id();
+/// # app.update();
+/// # app.world_mut().get_mut::<CellState>(entity).unwrap().0 = true;
+/// # app.update();
+/// # assert!(app.world().get::<BackBuffer<CellState>>(entity).unwrap().0);
+/// # app.update();
+/// # assert!(app.world().get::<CellState>(entity).unwrap().0);
+/// ```
+pub struct DoubleBufferedPlugin<T> {
+    schedule: InternedScheduleLabel,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> DoubleBufferedPlugin<T> {
+    /// Creates the plugin, copying the back buffer to the front in `schedule`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for DoubleBufferedPlugin<T> {
+    fn default() -> Self {
+        Self::new(Last)
+    }
+}
+
+impl<T> Debug for DoubleBufferedPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DoubleBufferedPlugin")
+            .field("schedule", &self.schedule)
+            .finish()
+    }
+}
+
+impl<T: Component + Clone> Plugin for DoubleBufferedPlugin<T> {
+    fn build(&self, app: &mut App) {
+        add_companion::<T, BackBuffer<T>>(app).add_systems(self.schedule, swap_buffers::<T>);
+    }
+}
+
+/// The back buffer of component `T`, kept by [`DoubleBufferedPlugin<T>`]
+///
+/// Use [`WriteBuffer<T>`] to write to it in queries.
+#[derive(Component, Debug)]
+pub struct BackBuffer<T: Component + Clone>(T);
+
+impl<T: Component + Clone> Deref for BackBuffer<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Component + Clone> Companion<T> for BackBuffer<T> {
+    fn new(value: &T) -> Self {
+        Self(value.clone())
+    }
+}
+
+fn swap_buffers<T: Component + Clone>(mut query: Query<(&mut T, &mut BackBuffer<T>)>) {
+    for (mut front, mut back) in query.iter_mut() {
+        if back.is_changed() {
+            front.clone_from(&back.0);
+        } else if front.is_changed() {
+            // T was written directly, so resync the back buffer from it
+            back.bypass_change_detection().0.clone_from(&front);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReadBufferQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct WriteBufferQ<T>(PhantomData<T>);
+
+/// Returns the front buffer of component `T`, which is the value it had when the buffers were
+/// last swapped
+///
+/// Requires [`DoubleBufferedPlugin<T>`] to be added to the app. This is the same as `&T`, since
+/// `T` is the front buffer, but makes it clear that the value is double buffered.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct CellState(bool);
+///
+/// fn example(query: Query<ReadBuffer<CellState>>) {
+///     let _: &CellState = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct CellState(bool);
+///
+/// fn bad_example(query: Query<ReadBuffer<CellState>>) {
+///     let _: &CellState = query.get_single().unwrap();
+/// }
+/// ```
+pub type ReadBuffer<T> = ModQ<ReadBufferQ<T>>;
+impl<T: Component + Clone> ModQuery for ReadBufferQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a T;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the back buffer of component `T`, mutably, which is copied to the front buffer the next
+/// time the buffers are swapped
+///
+/// Requires [`DoubleBufferedPlugin<T>`] to be added to the app. Since it doesn't access `T`
+/// mutably, systems that only read `T` can run in parallel with systems that write to it.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct CellState(bool);
+///
+/// fn example(mut query: Query<(ReadBuffer<CellState>, WriteBuffer<CellState>)>) {
+///     for (front, mut back) in query.iter_mut() {
+///         back.0 = !front.0;
+///     }
+/// }
+///
+/// fn read_back_example(query: Query<WriteBuffer<CellState>>) {
+///     let _: &CellState = query.get_single().unwrap();
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(DoubleBufferedPlugin::<CellState>::default())
+/// #     .add_systems(Update, (example, example));
+/// # let entity = app.world_mut().spawn(CellState(false)).id();
+/// # app.update();
+/// # assert!(app.world().get::<CellState>(entity).unwrap().0);
+/// # app.update();
+/// # assert!(!app.world().get::<CellState>(entity).unwrap().0);
+/// # let mut query = app.world_mut().query::<WriteBuffer<CellState>>();
+/// # let back: &CellState = query.iter(app.world()).next().unwrap();
+/// # assert!(!back.0);
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct CellState(bool);
+///
+/// fn bad_example(mut query: Query<WriteBuffer<CellState>>) {
+///     query.single_mut().0 = true;
+/// }
+/// ```
+pub type WriteBuffer<T> = ModQMut<WriteBufferQ<T>>;
+impl<T: Component + Clone> ModQueryMut for WriteBufferQ<T> {
+    type FromQuery = &'static mut BackBuffer<T>;
+    type ModItem<'a> = Mut<'a, T>;
+    type ReadOnly = AsDeref<BackBuffer<T>>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_unchanged(|back| &mut back.0)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
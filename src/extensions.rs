@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::mem::Discriminant;
 use std::ops::{Deref, DerefMut};
 
 use bevy::ecs::component::Component;
@@ -17,6 +18,10 @@ pub struct AsDerefQ<T>(PhantomData<T>);
 pub struct AsDerefMutQ<T>(PhantomData<T>);
 #[derive(Debug)]
 pub struct OrDefaultQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct DiscriminantOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct MappedTryIntoQ<T, U>(PhantomData<(T, U)>);
 
 /// Clones a type when it is retrieved
 ///
@@ -710,3 +715,200 @@ pub type AsDerefCopiedOrDefault<T> = OrDefault<AsDerefCopied<T>>;
 /// }
 /// ```
 pub type AsDerefClonedOrDefault<T> = OrDefault<AsDerefCloned<T>>;
+
+/// Returns the [`Discriminant`] of an enum component, so entities can be compared or grouped by
+/// variant without matching on it.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::mem::Discriminant;
+/// #[derive(Component)]
+/// enum AiState {
+///     Idle,
+///     Chasing(Entity),
+/// }
+///
+/// fn example(query: Query<DiscriminantOf<AiState>>) {
+///     let _: Discriminant<AiState> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let a = world.spawn(AiState::Chasing(Entity::PLACEHOLDER)).id();
+/// # let b = world.spawn(AiState::Chasing(Entity::PLACEHOLDER)).id();
+/// # let c = world.spawn(AiState::Idle).id();
+/// # let mut query = world.query::<DiscriminantOf<AiState>>();
+/// # assert_eq!(query.get(&world, a).unwrap(), query.get(&world, b).unwrap());
+/// # assert_ne!(query.get(&world, a).unwrap(), query.get(&world, c).unwrap());
+/// ```
+pub type DiscriminantOf<T> = ModQ<DiscriminantOfQ<T>>;
+impl<T: Component> ModQuery for DiscriminantOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Discriminant<T>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        std::mem::discriminant(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the [`Discriminant`] of the enum a component dereferences to (alias of
+/// `DiscriminantOf<AsDeref<T>>`)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::mem::Discriminant;
+/// enum Stance {
+///     Standing,
+///     Crouching,
+/// }
+///
+/// #[derive(Component, Deref)]
+/// struct CurrentStance(Stance);
+///
+/// fn example(query: Query<AsDerefDiscriminantOf<CurrentStance>>) {
+///     let _: Discriminant<Stance> = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter example: Outer type must implement Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::mem::Discriminant;
+/// enum Stance {
+///     Standing,
+///     Crouching,
+/// }
+///
+/// #[derive(Component)]
+/// struct CurrentStance(Stance);
+///
+/// fn bad_example(query: Query<AsDerefDiscriminantOf<CurrentStance>>) {
+///     let _: Discriminant<Stance> = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsDerefDiscriminantOf<T> = DiscriminantOf<AsDeref<T>>;
+impl<T: Component + Deref> ModQuery for DiscriminantOfQ<AsDeref<T>>
+where
+    <T as Deref>::Target: Sized,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Discriminant<<T as Deref>::Target>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        std::mem::discriminant(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the result of a fallible conversion of a component reference into `U`, or `None` if
+/// the conversion fails.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct NetId(u64);
+///
+/// struct SlotIndex(u32);
+///
+/// impl TryFrom<&NetId> for SlotIndex {
+///     type Error = std::num::TryFromIntError;
+///
+///     fn try_from(value: &NetId) -> Result<Self, Self::Error> {
+///         value.0.try_into().map(SlotIndex)
+///     }
+/// }
+///
+/// fn example(query: Query<MappedTryInto<NetId, SlotIndex>>) {
+///     let _: Option<SlotIndex> = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Reference to component must implement TryInto
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct NetId(u64);
+///
+/// struct SlotIndex(u32);
+///
+/// fn bad_example(query: Query<MappedTryInto<NetId, SlotIndex>>) {
+///     let _: Option<SlotIndex> = query.get_single().unwrap();
+/// }
+/// ```
+pub type MappedTryInto<T, U> = ModQ<MappedTryIntoQ<T, U>>;
+impl<T: Component, U> ModQuery for MappedTryIntoQ<T, U>
+where
+    for<'a> &'a T: TryInto<U>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<U>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.try_into().ok()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the result of a fallible conversion of a copy of the dereferenced value into `U`, or
+/// `None` if the conversion fails (alias of `MappedTryInto<AsDeref<T>, U>`)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct NetId(u64);
+///
+/// fn example(query: Query<AsDerefMappedTryInto<NetId, u32>>) {
+///     let _: Option<u32> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let small = world.spawn(NetId(12)).id();
+/// # let large = world.spawn(NetId(u64::MAX)).id();
+/// # let mut query = world.query::<AsDerefMappedTryInto<NetId, u32>>();
+/// # assert_eq!(query.get(&world, small).unwrap(), Some(12));
+/// # assert_eq!(query.get(&world, large).unwrap(), None);
+/// ```
+/// ## Counter example: Dereferenced type must implement Copy
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Names(Vec<String>);
+///
+/// fn bad_example(query: Query<AsDerefMappedTryInto<Names, Vec<String>>>) {
+///     let _: Option<Vec<String>> = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsDerefMappedTryInto<T, U> = MappedTryInto<AsDeref<T>, U>;
+impl<T: Component + Deref, U> ModQuery for MappedTryIntoQ<AsDeref<T>, U>
+where
+    <T as Deref>::Target: Copy + TryInto<U>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<U>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        (*t.deref()).try_into().ok()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
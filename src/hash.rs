@@ -0,0 +1,209 @@
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::utils::all_tuples;
+
+use super::base::{ModQ, ModQuery};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A [`Hasher`] implementing 64-bit FNV-1a, used for the hashes produced by this crate.
+///
+/// Unlike [`DefaultHasher`](std::hash::DefaultHasher), this hasher is unseeded: the same sequence
+/// of writes always produces the same output, in every process and on every platform. Integers are
+/// always hashed as little-endian bytes, and `usize`/`isize` are always hashed as 64-bit values.
+///
+/// This only covers the hasher itself. The bytes a type feeds it come from its [`Hash`] impl, and
+/// std does not guarantee that those stay the same across Rust versions (for example, for `str`,
+/// slices and derived enum discriminants). Only compare hashes between builds using the same
+/// compiler and dependencies, or implement [`Hash`] by hand for types whose hashes must be
+/// portable.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// use std::hash::{Hash, Hasher};
+///
+/// let mut hasher = StableHasher::default();
+/// 1u32.hash(&mut hasher);
+/// assert_eq!(hasher.finish(), 0xad2a_ca77_4798_5764);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16)
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_u64(i as i64 as u64)
+    }
+}
+
+#[derive(Debug)]
+pub struct ChecksumOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct HashOfQ<T>(PhantomData<T>);
+
+/// Returns a stable `u64` hash of all the listed components, for use in determinism checks.
+///
+/// The components are hashed in order with a [`StableHasher`], so the checksum for the same
+/// component values is the same across processes and platforms running the same build. See
+/// [`StableHasher`] for what is and isn't guaranteed. Implemented for tuples of 1 to 8
+/// components.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Hash)]
+/// struct GridPosition(i32, i32);
+///
+/// #[derive(Component, Hash)]
+/// struct Health(u32);
+///
+/// fn checksum_world(query: Query<ChecksumOf<(GridPosition, Health)>>) -> u64 {
+///     query
+///         .iter()
+///         .fold(0, |checksum, entity_checksum| checksum ^ entity_checksum)
+/// }
+/// ```
+/// ## Counter Example: Components must implement Hash
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Position(f32, f32);
+///
+/// fn bad_example(query: Query<ChecksumOf<(Position,)>>) {
+///     let _: u64 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ChecksumOf<T> = ModQ<ChecksumOfQ<T>>;
+
+macro_rules! impl_checksum_of {
+    ($($T:ident),*) => {
+        impl<$($T: Component + Hash),*> ModQuery for ChecksumOfQ<($($T,)*)> {
+            type FromQuery = ($(&'static $T,)*);
+            type ModItem<'a> = u64;
+
+            #[allow(non_snake_case)]
+            fn modify_reference(($($T,)*): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+                let mut hasher = StableHasher::default();
+                $($T.hash(&mut hasher);)*
+                hasher.finish()
+            }
+
+            fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+                item
+            }
+        }
+    };
+}
+
+all_tuples!(impl_checksum_of, 1, 8, T);
+
+/// Returns a stable `u64` hash of a component, using [`StableHasher`].
+///
+/// Useful as a deduplication or cache key, or to cheaply check if a large component is equal to
+/// its value on a previous frame without cloning it. This is the same value as
+/// [`ChecksumOf<(T,)>`](ChecksumOf).
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Hash)]
+/// struct Inventory(Vec<String>);
+///
+/// fn example(query: Query<HashOf<Inventory>>) {
+///     let _: u64 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let a = world.spawn(Inventory(vec!["sword".to_string()])).id();
+/// # let b = world.spawn(Inventory(vec!["sword".to_string()])).id();
+/// # let c = world.spawn(Inventory(vec!["shield".to_string()])).id();
+/// # let mut query = world.query::<HashOf<Inventory>>();
+/// # assert_eq!(query.get(&world, a).unwrap(), query.get(&world, b).unwrap());
+/// # assert_ne!(query.get(&world, a).unwrap(), query.get(&world, c).unwrap());
+/// ```
+/// ## Counter Example: Component must implement Hash
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Inventory(Vec<String>);
+///
+/// fn bad_example(query: Query<HashOf<Inventory>>) {
+///     let _: u64 = query.get_single().unwrap();
+/// }
+/// ```
+pub type HashOf<T> = ModQ<HashOfQ<T>>;
+impl<T: Component + Hash> ModQuery for HashOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = u64;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        let mut hasher = StableHasher::default();
+        t.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
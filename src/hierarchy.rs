@@ -0,0 +1,525 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{Has, WorldQuery};
+use bevy::ecs::system::{Query, SystemParam};
+use bevy::hierarchy::{Children, Parent};
+
+use super::base::{ModQ, ModQuery};
+use super::collection::{GetIndexQ, LenOfQ};
+
+/// Components that hold the targets of a relationship between entities, like [`Children`].
+///
+/// Bevy 0.15 has no general relationship components, so implement this for custom ones (targets,
+/// attackers, observers) to use them with [`RelatedCount`] and [`RelationshipTargetEntities`].
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Attackers(Vec<Entity>);
+///
+/// impl RelationshipTargetLike for Attackers {
+///     fn related(&self) -> &[Entity] {
+///         &self.0
+///     }
+/// }
+/// ```
+pub trait RelationshipTargetLike {
+    fn related(&self) -> &[Entity];
+}
+
+impl RelationshipTargetLike for Children {
+    #[inline]
+    fn related(&self) -> &[Entity] {
+        self
+    }
+}
+
+#[derive(Debug)]
+pub struct ParentEntityCopiedQ;
+#[derive(Debug)]
+pub struct ChildCountQ;
+#[derive(Debug)]
+pub struct NthChildQ<const N: usize>;
+#[derive(Debug)]
+pub struct LastChildQ;
+#[derive(Debug)]
+pub struct HasChildrenFlagQ;
+#[derive(Debug)]
+pub struct HasParentFlagQ;
+#[derive(Debug)]
+pub struct RelatedCountQ<R>(PhantomData<R>);
+#[derive(Debug)]
+pub struct RelationshipTargetEntitiesQ<R>(PhantomData<R>);
+
+/// Returns the [`Entity`] of an entity's [`Parent`], or `None` if it has no parent
+///
+/// `Parent` is the component later bevy versions call `ChildOf`. Requires the `bevy_hierarchy`
+/// feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ParentEntityCopied>) {
+///     let _: Option<Entity> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let parent = world.spawn_empty().id();
+/// # let child = world.spawn_empty().set_parent(parent).id();
+/// # let mut query = world.query::<ParentEntityCopied>();
+/// # assert_eq!(query.get(&world, child).unwrap(), Some(parent));
+/// # assert_eq!(query.get(&world, parent).unwrap(), None);
+/// ```
+/// ## Counter Example: The result is optional, since not every entity has a parent
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<ParentEntityCopied>) {
+///     let _: Entity = query.get_single().unwrap();
+/// }
+/// ```
+pub type ParentEntityCopied = ModQ<ParentEntityCopiedQ>;
+impl ModQuery for ParentEntityCopiedQ {
+    type FromQuery = Option<&'static Parent>;
+    type ModItem<'a> = Option<Entity>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map(Parent::get)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the number of [`Children`] an entity has, or 0 if it has none
+///
+/// Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ChildCount>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let parent = world.spawn_empty().with_children(|parent| {
+/// #     parent.spawn_empty();
+/// #     parent.spawn_empty();
+/// # }).id();
+/// # let mut query = world.query::<ChildCount>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), 2);
+/// # let childless = world.spawn_empty().id();
+/// # assert_eq!(query.get(&world, childless).unwrap(), 0);
+/// ```
+pub type ChildCount = ModQ<ChildCountQ>;
+impl ModQuery for ChildCountQ {
+    type FromQuery = Option<&'static Children>;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(0, |children| children.len())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the [`Entity`] of the `N`-th of an entity's [`Children`], or `None` if it doesn't have
+/// that many children
+///
+/// Useful when children occupy fixed slots, like a UI node whose label is always its first child.
+/// Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<NthChild<1>>) {
+///     let _: Option<Entity> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let first = world.spawn_empty().id();
+/// # let second = world.spawn_empty().id();
+/// # let parent = world.spawn_empty().add_children(&[first, second]).id();
+/// # let mut query = world.query::<NthChild<1>>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), Some(second));
+/// # let mut query = world.query::<NthChild<2>>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), None);
+/// ```
+/// ## Counter Example: The result is optional, since the child may not exist
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<NthChild<1>>) {
+///     let _: Entity = query.get_single().unwrap();
+/// }
+/// ```
+pub type NthChild<const N: usize> = ModQ<NthChildQ<N>>;
+impl<const N: usize> ModQuery for NthChildQ<N> {
+    type FromQuery = Option<&'static Children>;
+    type ModItem<'a> = Option<Entity>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.and_then(|children| children.get(N).copied())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the [`Entity`] of the first of an entity's [`Children`], or `None` if it has no
+/// children (alias of `NthChild<0>`)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<FirstChild>) {
+///     let _: Option<Entity> = query.get_single().unwrap();
+/// }
+/// ```
+pub type FirstChild = NthChild<0>;
+
+/// Returns the [`Entity`] of the last of an entity's [`Children`], or `None` if it has no
+/// children
+///
+/// Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<LastChild>) {
+///     let _: Option<Entity> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let first = world.spawn_empty().id();
+/// # let last = world.spawn_empty().id();
+/// # let parent = world.spawn_empty().add_children(&[first, last]).id();
+/// # let mut query = world.query::<LastChild>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), Some(last));
+/// # assert_eq!(query.get(&world, last).unwrap(), None);
+/// ```
+pub type LastChild = ModQ<LastChildQ>;
+impl ModQuery for LastChildQ {
+    type FromQuery = Option<&'static Children>;
+    type ModItem<'a> = Option<Entity>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.and_then(|children| children.last().copied())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity has any [`Children`]
+///
+/// Unlike a `With<Children>` filter, this includes entities without children, so a system can
+/// branch on it per entity. Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<HasChildrenFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let child = world.spawn_empty().id();
+/// # let parent = world.spawn_empty().add_child(child).id();
+/// # let mut query = world.query::<HasChildrenFlag>();
+/// # assert!(query.get(&world, parent).unwrap());
+/// # assert!(!query.get(&world, child).unwrap());
+/// ```
+/// ## Counter Example: HasChildrenFlag is data, not a filter (use `With<Children>` instead)
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, HasChildrenFlag>) {
+///     for _parent in query.iter() {}
+/// }
+/// ```
+pub type HasChildrenFlag = ModQ<HasChildrenFlagQ>;
+impl ModQuery for HasChildrenFlagQ {
+    type FromQuery = Option<&'static Children>;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.is_some_and(|children| !children.is_empty())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity has a [`Parent`]
+///
+/// Unlike a `With<Parent>` filter, this includes root entities, so a system can branch on it per
+/// entity. Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<HasParentFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let child = world.spawn_empty().id();
+/// # let parent = world.spawn_empty().add_child(child).id();
+/// # let mut query = world.query::<HasParentFlag>();
+/// # assert!(query.get(&world, child).unwrap());
+/// # assert!(!query.get(&world, parent).unwrap());
+/// ```
+/// ## Counter Example: HasParentFlag is data, not a filter (use `With<Parent>` instead)
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, HasParentFlag>) {
+///     for _child in query.iter() {}
+/// }
+/// ```
+pub type HasParentFlag = ModQ<HasParentFlagQ>;
+impl ModQuery for HasParentFlagQ {
+    type FromQuery = Has<Parent>;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the number of targets in a [`RelationshipTargetLike`] component, or 0 if the entity
+/// doesn't have it
+///
+/// `RelatedCount<Children>` is the same as [`ChildCount`]. Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Attackers(Vec<Entity>);
+///
+/// impl RelationshipTargetLike for Attackers {
+///     fn related(&self) -> &[Entity] {
+///         &self.0
+///     }
+/// }
+///
+/// fn example(query: Query<RelatedCount<Attackers>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let attacker = world.spawn_empty().id();
+/// # let target = world.spawn(Attackers(vec![attacker])).id();
+/// # let mut query = world.query::<RelatedCount<Attackers>>();
+/// # assert_eq!(query.get(&world, target).unwrap(), 1);
+/// # assert_eq!(query.get(&world, attacker).unwrap(), 0);
+/// ```
+/// ## Counter Example: Component must implement RelationshipTargetLike
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Attackers(Vec<Entity>);
+///
+/// fn bad_example(query: Query<RelatedCount<Attackers>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+/// ```
+pub type RelatedCount<R> = ModQ<RelatedCountQ<R>>;
+impl<R: Component + RelationshipTargetLike> ModQuery for RelatedCountQ<R> {
+    type FromQuery = Option<&'static R>;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(0, |r| r.related().len())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the targets in a [`RelationshipTargetLike`] component as `&[Entity]`, or an empty
+/// slice if the entity doesn't have it
+///
+/// Composes with [`LenOf`](crate::LenOf) and [`GetIndex`](crate::GetIndex). Requires the
+/// `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<RelationshipTargetEntities<Children>>) {
+///     let _: &[Entity] = query.get_single().unwrap();
+/// }
+///
+/// fn composed_example(
+///     query: Query<(
+///         LenOf<RelationshipTargetEntities<Children>>,
+///         GetIndex<RelationshipTargetEntities<Children>, 0>,
+///     )>,
+/// ) {
+///     let _: (usize, Option<&Entity>) = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let child = world.spawn_empty().id();
+/// # let parent = world.spawn_empty().add_child(child).id();
+/// # let mut query = world.query::<RelationshipTargetEntities<Children>>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), &[child]);
+/// # assert!(query.get(&world, child).unwrap().is_empty());
+/// # let mut query = world.query::<(
+/// #     LenOf<RelationshipTargetEntities<Children>>,
+/// #     GetIndex<RelationshipTargetEntities<Children>, 0>,
+/// # )>();
+/// # assert_eq!(query.get(&world, parent).unwrap(), (1, Some(&child)));
+/// # assert_eq!(query.get(&world, child).unwrap(), (0, None));
+/// ```
+/// ## Counter Example: Component must implement RelationshipTargetLike
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<RelationshipTargetEntities<Parent>>) {
+///     let _: &[Entity] = query.get_single().unwrap();
+/// }
+/// ```
+pub type RelationshipTargetEntities<R> = ModQ<RelationshipTargetEntitiesQ<R>>;
+impl<R: Component + RelationshipTargetLike> ModQuery for RelationshipTargetEntitiesQ<R> {
+    type FromQuery = Option<&'static R>;
+    type ModItem<'a> = &'a [Entity];
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(&[], |r| r.related())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+impl<R: Component + RelationshipTargetLike> ModQuery for LenOfQ<RelationshipTargetEntities<R>> {
+    type FromQuery = Option<&'static R>;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(0, |r| r.related().len())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+impl<R: Component + RelationshipTargetLike, const I: usize> ModQuery
+    for GetIndexQ<RelationshipTargetEntities<R>, I>
+{
+    type FromQuery = Option<&'static R>;
+    type ModItem<'a> = Option<&'a Entity>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.and_then(|r| r.related().get(I))
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// A [`SystemParam`] that dereferences a component of an entity or, if it doesn't have one, of its
+/// nearest ancestor
+///
+/// [`InheritedValue::get`] walks up [`Parent`]s until it finds an entity with the component, which
+/// is useful for properties that children inherit, like opacity, team or locale.
+///
+/// Requires the `bevy_hierarchy` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Deref)]
+/// struct Team(u8);
+///
+/// fn example(teams: InheritedValue<Team>, units: Query<Entity, With<Name>>) {
+///     for unit in units.iter() {
+///         let _: Option<&u8> = teams.get(unit);
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # let grandchild = world.spawn_empty().id();
+/// # let child = world.spawn_empty().add_child(grandchild).id();
+/// # let root = world.spawn(Team(1)).add_child(child).id();
+/// # let overridden = world.spawn(Team(2)).set_parent(root).id();
+/// # let orphan = world.spawn_empty().id();
+/// # world.run_system_once(move |teams: InheritedValue<Team>| {
+/// #     assert_eq!(teams.get(root), Some(&1));
+/// #     assert_eq!(teams.get(grandchild), Some(&1));
+/// #     assert_eq!(teams.get(overridden), Some(&2));
+/// #     assert_eq!(teams.get(orphan), None);
+/// # }).unwrap();
+/// ```
+/// ## Counter Example: Component must be Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Team(u8);
+///
+/// fn bad_example(teams: InheritedValue<Team>, units: Query<Entity, With<Name>>) {
+///     for unit in units.iter() {
+///         let _: Option<&u8> = teams.get(unit);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct InheritedValue<'w, 's, T: Component + Deref> {
+    query: Query<'w, 's, (Option<&'static T>, Option<&'static Parent>)>,
+}
+
+impl<T: Component + Deref> InheritedValue<'_, '_, T> {
+    /// Returns the dereferenced component of the entity or its nearest ancestor that has one.
+    pub fn get(&self, mut entity: Entity) -> Option<&T::Target> {
+        loop {
+            match self.query.get(entity).ok()? {
+                (Some(component), _) => return Some(component.deref()),
+                (None, Some(parent)) => entity = parent.get(),
+                (None, None) => return None,
+            }
+        }
+    }
+}
+
+impl<T: Component + Deref> Debug for InheritedValue<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InheritedValue").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::vec_deque::{self, VecDeque};
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+use bevy::app::{App, Last, Plugin};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::ecs::system::Query;
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+
+/// A plugin that records the value of component `T` at the end of every frame, keeping the last
+/// `N`, for use with [`History`]
+///
+/// The values are stored in a [`HistoryBuffer<T, N>`] component, which is inserted and removed
+/// along with `T` using commands. `N` must not be 0.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Position(Vec2);
+///
+/// App::new().add_plugins(HistoryPlugin::<Position, 16>::default());
+/// ```
+/// ## Counter Example: History length must not be 0
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Position(Vec2);
+///
+/// App::new().add_plugins(HistoryPlugin::<Position, 0>::default());
+/// ```
+pub struct HistoryPlugin<T, const N: usize>(PhantomData<fn() -> T>);
+
+impl<T, const N: usize> Default for HistoryPlugin<T, N> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T, const N: usize> Debug for HistoryPlugin<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HistoryPlugin").finish()
+    }
+}
+
+impl<T: Component + Clone, const N: usize> Plugin for HistoryPlugin<T, N> {
+    fn build(&self, app: &mut App) {
+        const { assert!(N != 0, "HistoryPlugin N must not be 0") };
+        add_companion::<T, HistoryBuffer<T, N>>(app).add_systems(Last, record_history::<T, N>);
+    }
+}
+
+/// The values component `T` had at the end of the last `N` frames, recorded by
+/// [`HistoryPlugin<T, N>`]
+///
+/// Use [`History<T, N>`] to read it in queries.
+#[derive(Component, Debug)]
+pub struct HistoryBuffer<T: Component + Clone, const N: usize>(VecDeque<T>);
+
+impl<T: Component + Clone, const N: usize> Companion<T> for HistoryBuffer<T, N> {
+    fn new(_: &T) -> Self {
+        Self(VecDeque::with_capacity(N))
+    }
+}
+
+fn record_history<T: Component + Clone, const N: usize>(
+    mut query: Query<(&T, &mut HistoryBuffer<T, N>)>,
+) {
+    for (value, mut history) in query.iter_mut() {
+        let value = if history.0.len() == N {
+            let mut oldest = history.0.pop_back().unwrap();
+            oldest.clone_from(value);
+            oldest
+        } else {
+            value.clone()
+        };
+        history.0.push_front(value);
+    }
+}
+
+#[derive(Debug)]
+pub struct HistoryQ<T, const N: usize>(PhantomData<T>);
+
+/// Returns an iterator over the values component `T` had at the end of each of the last `N`
+/// frames, most recent first
+///
+/// Requires [`HistoryPlugin<T, N>`] to be added to the app. The iterator is empty until the end of
+/// the frame `T` was added in.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Position(Vec2);
+///
+/// fn example(query: Query<History<Position, 16>>) {
+///     for trail in query.iter() {
+///         let _: Vec<Vec2> = trail.map(|position| position.0).collect();
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(HistoryPlugin::<Position, 2>::default());
+/// # let entity = app.world_mut().spawn(Position(Vec2::ZERO)).id();
+/// # let mut query = app.world_mut().query::<History<Position, 2>>();
+/// # let mut history = |app: &App| -> Vec<f32> {
+/// #     query.single(app.world()).map(|position| position.0.x).collect()
+/// # };
+/// # app.update();
+/// # assert_eq!(history(&app), [0.0]);
+/// # app.world_mut().get_mut::<Position>(entity).unwrap().0.x = 1.0;
+/// # app.update();
+/// # assert_eq!(history(&app), [1.0, 0.0]);
+/// # app.world_mut().get_mut::<Position>(entity).unwrap().0.x = 2.0;
+/// # app.update();
+/// # assert_eq!(history(&app), [2.0, 1.0]);
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Position(Vec2);
+///
+/// fn bad_example(query: Query<History<Position, 16>>) {
+///     let _ = query.get_single().unwrap();
+/// }
+/// ```
+pub type History<T, const N: usize> = ModQ<HistoryQ<T, N>>;
+impl<T: Component + Clone, const N: usize> ModQuery for HistoryQ<T, N> {
+    type FromQuery = &'static HistoryBuffer<T, N>;
+    type ModItem<'a> = vec_deque::Iter<'a, T>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.0.iter()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
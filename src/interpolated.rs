@@ -0,0 +1,190 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::app::{App, FixedFirst, Plugin, RunFixedMainLoop, RunFixedMainLoopSystem};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::{Query, Res};
+use bevy::math::StableInterpolate;
+use bevy::time::{Fixed, Time};
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+
+/// A plugin that records the dereferenced value of component `T` at the start of every fixed
+/// timestep, and blends it with the current value every frame, for use with [`Interpolated`]
+///
+/// The values are stored in an [`InterpolatedValue<T>`] component, which is inserted and removed
+/// along with `T` using commands. The blend is updated after the fixed main loop, in
+/// [`RunFixedMainLoopSystem::AfterFixedMainLoop`], using the overstep fraction of
+/// [`Time<Fixed>`]. Requires bevy's `TimePlugin`.
+///
+/// Requires the `bevy_math` and `bevy_time` features.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct PhysicsPosition(Vec2);
+///
+/// App::new().add_plugins(InterpolatedPlugin::<PhysicsPosition>::default());
+/// ```
+pub struct InterpolatedPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for InterpolatedPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Debug for InterpolatedPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InterpolatedPlugin").finish()
+    }
+}
+
+impl<T> Plugin for InterpolatedPlugin<T>
+where
+    T: Component + Deref<Target: StableInterpolate + Send + Sync>,
+{
+    fn build(&self, app: &mut App) {
+        add_companion::<T, InterpolatedValue<T>>(app)
+            .add_systems(FixedFirst, record_fixed_previous::<T>)
+            .add_systems(
+                RunFixedMainLoop,
+                interpolate::<T>.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+            );
+    }
+}
+
+/// The dereferenced value of component `T` at the start of the last fixed timestep, and its
+/// blend with the current value, recorded by [`InterpolatedPlugin<T>`]
+///
+/// Use [`Interpolated<T>`] to read it in queries.
+#[derive(Component)]
+pub struct InterpolatedValue<T: Deref<Target: Sized + Send + Sync> + Send + Sync + 'static> {
+    previous: T::Target,
+    interpolated: T::Target,
+}
+
+impl<T> Debug for InterpolatedValue<T>
+where
+    T: Deref<Target: Debug + Sized + Send + Sync> + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterpolatedValue")
+            .field("previous", &self.previous)
+            .field("interpolated", &self.interpolated)
+            .finish()
+    }
+}
+
+impl<T> Companion<T> for InterpolatedValue<T>
+where
+    T: Component + Deref<Target: StableInterpolate + Send + Sync>,
+{
+    fn new(value: &T) -> Self {
+        Self {
+            previous: value.deref().clone(),
+            interpolated: value.deref().clone(),
+        }
+    }
+}
+
+fn record_fixed_previous<T>(mut query: Query<(&T, &mut InterpolatedValue<T>)>)
+where
+    T: Component + Deref<Target: StableInterpolate + Send + Sync>,
+{
+    for (value, mut interpolated) in query.iter_mut() {
+        interpolated.previous.clone_from(value.deref());
+    }
+}
+
+fn interpolate<T>(mut query: Query<(&T, &mut InterpolatedValue<T>)>, time: Res<Time<Fixed>>)
+where
+    T: Component + Deref<Target: StableInterpolate + Send + Sync>,
+{
+    let overstep_fraction = time.overstep_fraction();
+    for (value, mut interpolated) in query.iter_mut() {
+        let interpolated = &mut *interpolated;
+        interpolated.interpolated = interpolated
+            .previous
+            .interpolate_stable(value.deref(), overstep_fraction);
+    }
+}
+
+#[derive(Debug)]
+pub struct InterpolatedQ<T>(PhantomData<T>);
+
+/// Returns the dereferenced value of component `T`, blended from its value at the start of the
+/// last fixed timestep to its current value by how far into the next timestep this frame is
+///
+/// Useful for smoothly rendering components that are only updated in
+/// [`FixedUpdate`](bevy::app::FixedUpdate). Requires [`InterpolatedPlugin<T>`] to be added to the
+/// app.
+///
+/// Requires the `bevy_math` and `bevy_time` features.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::time::{TimePlugin, TimeUpdateStrategy};
+/// # use std::time::Duration;
+/// #[derive(Component, Deref, DerefMut)]
+/// struct PhysicsPosition(Vec2);
+///
+/// fn example(mut query: Query<(&mut Transform, Interpolated<PhysicsPosition>)>) {
+///     for (mut transform, position) in query.iter_mut() {
+///         transform.translation = position.extend(0.0);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((TimePlugin, InterpolatedPlugin::<PhysicsPosition>::default()))
+/// #     .insert_resource(Time::<Fixed>::from_seconds(1.0))
+/// #     .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(250)))
+/// #     .add_systems(FixedUpdate, |mut query: Query<&mut PhysicsPosition>| {
+/// #         query.single_mut().x += 4.0;
+/// #     });
+/// # app.world_mut().spawn(PhysicsPosition(Vec2::ZERO));
+/// # let mut query = app.world_mut().query::<(&PhysicsPosition, Interpolated<PhysicsPosition>)>();
+/// # let mut results = Vec::new();
+/// # for _ in 0..8 {
+/// #     app.update();
+/// #     let (position, interpolated) = query.single(app.world());
+/// #     results.push((position.x, interpolated.x));
+/// # }
+/// # // The first update has no delta, then every fourth update runs a fixed timestep
+/// # assert_eq!(results[4..], [(4.0, 0.0), (4.0, 1.0), (4.0, 2.0), (4.0, 3.0)]);
+/// ```
+/// ## Counter Example: Dereferenced type must be interpolatable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Level(u32);
+///
+/// fn bad_example(query: Query<Interpolated<Level>>) {
+///     let _: &u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type Interpolated<T> = ModQ<InterpolatedQ<T>>;
+impl<T> ModQuery for InterpolatedQ<T>
+where
+    T: Component + Deref<Target: StableInterpolate + Send + Sync>,
+{
+    type FromQuery = &'static InterpolatedValue<T>;
+    type ModItem<'a> = &'a T::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        &t.interpolated
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
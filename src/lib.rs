@@ -1,20 +1,99 @@
 #![doc = include_str!("../README.md")]
 #![warn(missing_debug_implementations)]
 
+#[cfg(feature = "bevy_asset")]
+mod asset;
 mod base;
+mod bits;
+mod boolean;
+#[cfg(feature = "bytemuck")]
+mod bytes;
+mod cache;
+#[cfg(feature = "bevy_render")]
+mod camera;
+mod collection;
+mod companion;
+mod conditions;
+mod double_buffer;
 mod extensions;
+mod hash;
+#[cfg(feature = "bevy_hierarchy")]
+mod hierarchy;
+mod history;
+#[cfg(all(feature = "bevy_math", feature = "bevy_time"))]
+mod interpolated;
+#[cfg(feature = "bevy_math")]
+mod math;
+#[cfg(feature = "bevy_core")]
+mod name;
+mod numeric;
 mod or_const;
+mod previous;
+mod provider;
+mod query_ext;
+mod smoothed;
+#[cfg(feature = "bevy_sprite")]
+mod sprite;
+mod start_of_frame;
+mod string;
+mod sync;
+mod system_params;
+#[cfg(feature = "bevy_text")]
+mod text;
+#[cfg(feature = "bevy_time")]
+mod time;
+#[cfg(feature = "bevy_transform")]
+mod transform;
+#[cfg(feature = "bevy_ui")]
+mod ui;
+#[cfg(feature = "bevy_render")]
+mod visibility;
 
 /// Prelude module - Contains only the parts of the crate that are useful to consumers
 /// Everything in this module can also be imported from the crate directly, but you
 /// can import `bevy_query_ext::prelude::*` over `bevy_query_ext::*` to avoid
 /// importing our internal modules.
 pub mod prelude {
+    #[cfg(feature = "bevy_asset")]
+    pub use super::asset::{AssetIdOf, HandleCloned, HandleLike};
+    pub use super::bits::{BitFlagSet, BitMasked, Bits};
+    pub use super::boolean::{AsFilter, BoolAll, BoolAny, BoolNot};
+    #[cfg(feature = "bytemuck")]
+    pub use super::bytes::{AsBytes, AsDerefAsBytes};
+    pub use super::cache::{CachedCloned, CachedClonedPlugin, ClonedCache};
+    #[cfg(feature = "bevy_render")]
+    pub use super::camera::CameraIsActiveFlag;
+    pub use super::collection::{
+        AsSlice, Collection, CountOfIter, FirstOfSlice, GetIndex, IsEmptyOf, KeyedGet, LastOfSlice,
+        LenOf, MapLike, MaxElementOf, MinElementOf, SliceLike, SumOfElements,
+    };
+    pub use super::conditions::{any_changed_and_true, any_with_deref_true, resource_deref_equals};
+    pub use super::double_buffer::{BackBuffer, DoubleBufferedPlugin, ReadBuffer, WriteBuffer};
     pub use super::extensions::{
         AsDeref, AsDerefCloned, AsDerefClonedOfClonedOrDefault, AsDerefClonedOrDefault,
         AsDerefCopied, AsDerefCopiedOfClonedOrDefault, AsDerefCopiedOfCopiedOrDefault,
-        AsDerefCopiedOrDefault, AsDerefMut, Cloned, ClonedOrDefault, Copied, CopiedOrDefault,
-        OrDefault,
+        AsDerefCopiedOrDefault, AsDerefDiscriminantOf, AsDerefMappedTryInto, AsDerefMut, Cloned,
+        ClonedOrDefault, Copied, CopiedOrDefault, DiscriminantOf, MappedTryInto, OrDefault,
+    };
+    pub use super::hash::{ChecksumOf, HashOf, StableHasher};
+    #[cfg(feature = "bevy_hierarchy")]
+    pub use super::hierarchy::{
+        ChildCount, FirstChild, HasChildrenFlag, HasParentFlag, InheritedValue, LastChild,
+        NthChild, ParentEntityCopied, RelatedCount, RelationshipTargetEntities,
+        RelationshipTargetLike,
+    };
+    pub use super::history::{History, HistoryBuffer, HistoryPlugin};
+    #[cfg(all(feature = "bevy_math", feature = "bevy_time"))]
+    pub use super::interpolated::{Interpolated, InterpolatedPlugin, InterpolatedValue};
+    #[cfg(feature = "bevy_math")]
+    pub use super::math::{VecLengthOf, VecLengthSquaredOf, VecNormalizedOf, VectorLike};
+    #[cfg(feature = "bevy_core")]
+    pub use super::name::{NameOrEntityString, NameStr};
+    pub use super::numeric::{
+        AbsOf, AsDegrees, AsF32, AsF64, AsFloat, AsRadians, Average, AverageOf,
+        ClampedByComponents, ClampedTo, DiffOf, Difference, Float, InRangeFlag, Integer,
+        NormalizedU8, ProductOf, Quantized, RatioOf, RatioOfF64, RoundedTo, SaturatingCastTo,
+        ScaledBy, Signed, SignumOf, SumOf, WrappedRadians,
     };
     pub use super::or_const::{
         AsDerefOrBool, AsDerefOrChar, AsDerefOrI128, AsDerefOrI16, AsDerefOrI32, AsDerefOrI64,
@@ -22,6 +101,47 @@ pub mod prelude {
         AsDerefOrU8, AsDerefOrUsize, OrBool, OrChar, OrI128, OrI16, OrI32, OrI64, OrI8, OrIsize,
         OrU128, OrU16, OrU32, OrU64, OrU8, OrUsize,
     };
+    pub use super::previous::{
+        Delta, Prev, Previous, PreviousPlugin, ValueChanged, ValueChangedPlugin,
+    };
+    #[cfg(feature = "bevy_time")]
+    pub use super::previous::{RateOf, RateOfChange};
+    pub use super::provider::Provider;
+    pub use super::query_ext::{EntityValuesExt, QueryValuesExt, WorldQueryExt};
+    pub use super::smoothed::{Smoothable, Smoothed, SmoothedPlugin, SmoothedValue, Smoothing};
+    #[cfg(feature = "bevy_sprite")]
+    pub use super::sprite::{
+        SpriteColorOf, SpriteFlipXFlag, SpriteFlipYFlag, TextureAtlasIndexMut, TextureAtlasIndexOf,
+    };
+    pub use super::start_of_frame::{StartOfFrame, StartOfFramePlugin, StartOfFrameValue};
+    pub use super::string::{
+        AsStr, AsStrOr, ContainsSubstrFlag, CowStrOf, CowStrOr, DebugString, DisplayString, OrStr,
+        StrCharCount, StrLen, TrimmedStr,
+    };
+    pub use super::sync::{
+        ArcCloned, ArcLike, AtomicLike, AtomicLoaded, LoadAcquire, LoadRelaxed, LoadSeqCst,
+        OnceLike, OnceLockGet, OnceLockGetOr, WeakLike, WeakUpgraded,
+    };
+    pub use super::system_params::{
+        ClonedRes, CopiedRes, DerefRes, DerefResMut, EnsureDefault, IndexedQuery, QueuedMut,
+        ResOrDefault, SingleCopied, SingleDeref, WriteQueue,
+    };
+    #[cfg(feature = "bevy_text")]
+    pub use super::text::TextStrOf;
+    #[cfg(feature = "bevy_time")]
+    pub use super::time::{
+        StopwatchElapsedSecs, TimerFinishedFlag, TimerFractionOf, TimerJustFinishedFlag,
+        TimerRemainingSecs,
+    };
+    #[cfg(feature = "bevy_transform")]
+    pub use super::transform::{
+        ForwardOf, GlobalTranslationCopied, RightOf, RotationOf, ScaleOf, TransformLike,
+        Translation2d, Translation2dMut, Translation2dView, TranslationCopied,
+    };
+    #[cfg(feature = "bevy_ui")]
+    pub use super::ui::{ComputedNodeSizeOf, IsHoveredFlag, IsPressedFlag};
+    #[cfg(feature = "bevy_render")]
+    pub use super::visibility::{IsVisibleFlag, RenderLayersContains, ViewVisibilityFlag};
 }
 #[doc(inline)]
 pub use self::prelude::*;
@@ -0,0 +1,198 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::math::{Vec2, Vec3, Vec3A, Vec4};
+
+use super::base::{ModQ, ModQuery};
+
+/// `f32` vector types from `bevy::math`.
+///
+/// Used by adapters such as [`VecLengthOf`] so they work the same on `Vec2`, `Vec3`, `Vec3A` and
+/// `Vec4`.
+pub trait VectorLike {
+    fn length(&self) -> f32;
+    fn length_squared(&self) -> f32;
+    fn normalize_or_zero(&self) -> Self;
+}
+
+macro_rules! impl_vector_like {
+    ($($t:ty),*) => {
+        $(
+            impl VectorLike for $t {
+                #[inline]
+                fn length(&self) -> f32 {
+                    <$t>::length(*self)
+                }
+
+                #[inline]
+                fn length_squared(&self) -> f32 {
+                    <$t>::length_squared(*self)
+                }
+
+                #[inline]
+                fn normalize_or_zero(&self) -> Self {
+                    <$t>::normalize_or_zero(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_vector_like!(Vec2, Vec3, Vec3A, Vec4);
+
+#[derive(Debug)]
+pub struct VecLengthOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct VecLengthSquaredOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct VecNormalizedOfQ<T>(PhantomData<T>);
+
+/// Returns the length of the vector a component dereferences to
+///
+/// Requires the `bevy_math` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Velocity(Vec2);
+///
+/// fn example(query: Query<VecLengthOf<Velocity>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Velocity(Vec2::new(3.0, 4.0)));
+/// # let mut query = world.query::<VecLengthOf<Velocity>>();
+/// # assert_eq!(query.single(&world), 5.0);
+/// ```
+/// ## Counter Example: Dereferenced type must be an f32 vector
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Velocity(IVec2);
+///
+/// fn bad_example(query: Query<VecLengthOf<Velocity>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type VecLengthOf<T> = ModQ<VecLengthOfQ<T>>;
+impl<T: Component + Deref> ModQuery for VecLengthOfQ<T>
+where
+    <T as Deref>::Target: VectorLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        VectorLike::length(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the squared length of the vector a component dereferences to
+///
+/// Cheaper than [`VecLengthOf`], and enough for comparing against a squared threshold. Requires
+/// the `bevy_math` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Offset(Vec3);
+///
+/// fn example(query: Query<VecLengthSquaredOf<Offset>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Offset(Vec3::new(1.0, 2.0, 2.0)));
+/// # let mut query = world.query::<VecLengthSquaredOf<Offset>>();
+/// # assert_eq!(query.single(&world), 9.0);
+/// ```
+/// ## Counter Example: Dereferenced type must be an f32 vector
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Offset(bevy::math::DVec3);
+///
+/// fn bad_example(query: Query<VecLengthSquaredOf<Offset>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type VecLengthSquaredOf<T> = ModQ<VecLengthSquaredOfQ<T>>;
+impl<T: Component + Deref> ModQuery for VecLengthSquaredOfQ<T>
+where
+    <T as Deref>::Target: VectorLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        VectorLike::length_squared(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the vector a component dereferences to normalized, or zero if it can't be normalized
+/// (because it is zero, very close to zero, or not finite)
+///
+/// Requires the `bevy_math` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Velocity(Vec2);
+///
+/// fn example(query: Query<VecNormalizedOf<Velocity>>) {
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let moving = world.spawn(Velocity(Vec2::new(3.0, 4.0))).id();
+/// # let still = world.spawn(Velocity(Vec2::ZERO)).id();
+/// # let mut query = world.query::<VecNormalizedOf<Velocity>>();
+/// # assert_eq!(query.get(&world, moving).unwrap(), Vec2::new(0.6, 0.8));
+/// # assert_eq!(query.get(&world, still).unwrap(), Vec2::ZERO);
+/// ```
+/// ## Counter Example: Dereferenced type must be an f32 vector
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Velocity(IVec2);
+///
+/// fn bad_example(query: Query<VecNormalizedOf<Velocity>>) {
+///     let _: IVec2 = query.get_single().unwrap();
+/// }
+/// ```
+pub type VecNormalizedOf<T> = ModQ<VecNormalizedOfQ<T>>;
+impl<T: Component + Deref> ModQuery for VecNormalizedOfQ<T>
+where
+    <T as Deref>::Target: VectorLike + Sized,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        VectorLike::normalize_or_zero(t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::core::Name;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct NameStrQ;
+#[derive(Debug)]
+pub struct NameOrEntityStringQ;
+
+/// Returns the `&str` of an entity's [`Name`]
+///
+/// Requires the `bevy_core` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<NameStr>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Name::new("Goblin"));
+/// # let mut query = world.query::<NameStr>();
+/// # assert_eq!(query.single(&world), "Goblin");
+/// ```
+/// ## Counter Example: NameStr is read-only
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(mut query: Query<NameStr>) {
+///     for name in query.iter_mut() {
+///         name.make_ascii_uppercase();
+///     }
+/// }
+/// ```
+pub type NameStr = ModQ<NameStrQ>;
+impl ModQuery for NameStrQ {
+    type FromQuery = &'static Name;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.as_str()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns an entity's [`Name`] as a `String`, or the entity id formatted with `Display` if it
+/// has no name
+///
+/// Useful for log lines and debug UIs. Requires the `bevy_core` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<NameOrEntityString>) {
+///     for identity in query.iter() {
+///         let _: String = identity;
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # let named = world.spawn(Name::new("Goblin")).id();
+/// # let unnamed = world.spawn_empty().id();
+/// # let mut query = world.query::<NameOrEntityString>();
+/// # assert_eq!(query.get(&world, named).unwrap(), "Goblin");
+/// # assert_eq!(query.get(&world, unnamed).unwrap(), unnamed.to_string());
+/// ```
+/// ## Counter Example: The result is owned, not borrowed
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<NameOrEntityString>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type NameOrEntityString = ModQ<NameOrEntityStringQ>;
+impl ModQuery for NameOrEntityStringQ {
+    type FromQuery = (Entity, Option<&'static Name>);
+    type ModItem<'a> = String;
+
+    fn modify_reference(
+        (entity, name): <Self::FromQuery as WorldQuery>::Item<'_>,
+    ) -> Self::ModItem<'_> {
+        match name {
+            Some(name) => name.as_str().to_string(),
+            None => entity.to_string(),
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,1506 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Deref, Mul, RangeInclusive};
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::math::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec3A, Vec4};
+use bevy::utils::all_tuples;
+
+use super::base::{ModQ, ModQuery};
+use super::provider::Provider;
+
+#[derive(Debug)]
+pub struct SumOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct DiffOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ProductOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct AverageOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct RatioOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct RatioOfF64Q<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ClampedByComponentsQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ScaledByQ<T, const NUM: i64, const DEN: i64>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ClampedToQ<T, const MIN: i64, const MAX: i64>(PhantomData<T>);
+#[derive(Debug)]
+pub struct SaturatingCastToQ<T, U>(PhantomData<(T, U)>);
+#[derive(Debug)]
+pub struct AsF32Q<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct AsF64Q<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct AbsOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct SignumOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct NormalizedU8Q<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct QuantizedQ<T, R, const BITS: u8>(PhantomData<(T, R)>);
+#[derive(Debug)]
+pub struct AsDegreesQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct AsRadiansQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct WrappedRadiansQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct RoundedToQ<T, const DECIMALS: u32>(PhantomData<T>);
+#[derive(Debug)]
+pub struct InRangeFlagQ<T, const MIN: i64, const MAX: i64>(PhantomData<T>);
+
+/// Primitive numeric types that can be converted to floating point values (a la `as` casts).
+///
+/// Used by adapters such as [`RatioOf`] that always produce floating point values.
+pub trait AsFloat: Copy {
+    fn as_f32(self) -> f32;
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_float {
+    ($($t:ty),*) => {
+        $(
+            impl AsFloat for $t {
+                #[inline]
+                fn as_f32(self) -> f32 {
+                    self as f32
+                }
+
+                #[inline]
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_as_float!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Primitive integer types (except `u128`) that can be widened to an `i128` without loss.
+///
+/// Used by adapters such as [`ScaledBy`] to do integer math without overflowing, then saturate
+/// the result back to the original type.
+pub trait Integer: Copy {
+    fn to_i128(self) -> i128;
+    fn saturating_from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                #[inline]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                #[inline]
+                fn saturating_from_i128(value: i128) -> Self {
+                    value.clamp(<$t>::MIN as i128, <$t>::MAX as i128) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+/// Primitive signed numeric types, used by [`AbsOf`] and [`SignumOf`].
+///
+/// For integers, the absolute value of `MIN` saturates to `MAX` instead of overflowing.
+pub trait Signed: Copy {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed_int {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    self.saturating_abs()
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed_float {
+    ($($t:ty),*) => {
+        $(
+            impl Signed for $t {
+                #[inline]
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+impl_signed_float!(f32, f64);
+
+/// Primitive floating point types, used by adapters such as [`AsDegrees`] that only make sense
+/// for floats.
+pub trait Float: Copy {
+    const TAU: Self;
+
+    fn to_degrees(self) -> Self;
+    fn to_radians(self) -> Self;
+    /// Wraps an angle in radians to `[0, 2π)`
+    fn wrap_radians(self) -> Self;
+    /// Rounds to the given number of decimal places, with halfway cases rounded away from zero
+    fn round_to_decimals(self, decimals: u32) -> Self;
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(
+            impl Float for $t {
+                const TAU: Self = std::$t::consts::TAU;
+
+                #[inline]
+                fn to_degrees(self) -> Self {
+                    <$t>::to_degrees(self)
+                }
+
+                #[inline]
+                fn to_radians(self) -> Self {
+                    <$t>::to_radians(self)
+                }
+
+                #[inline]
+                fn wrap_radians(self) -> Self {
+                    // rem_euclid can round up to exactly TAU for tiny negative values
+                    let wrapped = self.rem_euclid(Self::TAU);
+                    if wrapped == Self::TAU {
+                        0.0
+                    } else {
+                        wrapped
+                    }
+                }
+
+                #[inline]
+                fn round_to_decimals(self, decimals: u32) -> Self {
+                    let factor = <$t>::powi(10.0, decimals as i32);
+                    (self * factor).round() / factor
+                }
+            }
+        )*
+    };
+}
+
+impl_float!(f32, f64);
+
+/// Primitive numeric types that can be averaged without overflowing, used by [`AverageOf`].
+///
+/// Integers are averaged without ever computing the full sum, and the result is truncated
+/// towards zero, as with regular integer division. `values` must contain between 1 and 8
+/// elements.
+pub trait Average: Copy {
+    fn average(values: &[Self]) -> Self;
+}
+
+macro_rules! impl_average_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl Average for $t {
+                #[inline]
+                fn average(values: &[Self]) -> Self {
+                    let n = values.len() as $t;
+                    let (quotient, remainder) = values
+                        .iter()
+                        .fold((0, 0), |(q, r), &value| (q + value / n, r + value % n));
+                    quotient + remainder / n
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_average_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Average for $t {
+                #[inline]
+                fn average(values: &[Self]) -> Self {
+                    let n = values.len() as $t;
+                    let (quotient, remainder) = values
+                        .iter()
+                        .fold((0, 0), |(q, r), &value| (q + value / n, r + value % n));
+                    // quotient * n + remainder is the exact sum, so this is the floored mean
+                    let floor = quotient + remainder.div_euclid(n);
+                    if floor < 0 && remainder.rem_euclid(n) != 0 {
+                        floor + 1
+                    } else {
+                        floor
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_average_float {
+    ($($t:ty),*) => {
+        $(
+            impl Average for $t {
+                #[inline]
+                fn average(values: &[Self]) -> Self {
+                    let n = values.len() as $t;
+                    values.iter().map(|&value| value / n).sum()
+                }
+            }
+        )*
+    };
+}
+
+impl_average_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_average_signed!(i8, i16, i32, i64, i128, isize);
+impl_average_float!(f32, f64);
+
+/// Numeric types whose difference can be computed without overflowing, used by [`DiffOf`] and
+/// [`Delta`](crate::Delta).
+///
+/// Integers are widened to the next larger signed type, so the difference of two unsigned values
+/// can be negative instead of panicking on underflow. There is no wider type for 128-bit
+/// integers, so their difference saturates to an `i128`. Floats and float vectors subtract as
+/// usual.
+pub trait Difference: Copy {
+    type Output;
+
+    fn difference(self, rhs: Self) -> Self::Output;
+}
+
+macro_rules! impl_difference_widened {
+    ($($t:ty => $out:ty),*) => {
+        $(
+            impl Difference for $t {
+                type Output = $out;
+
+                #[inline]
+                fn difference(self, rhs: Self) -> Self::Output {
+                    self as $out - rhs as $out
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_difference_sub {
+    ($($t:ty),*) => {
+        $(
+            impl Difference for $t {
+                type Output = $t;
+
+                #[inline]
+                fn difference(self, rhs: Self) -> Self::Output {
+                    self - rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_difference_widened!(
+    u8 => i16, u16 => i32, u32 => i64, u64 => i128, usize => i128,
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128, isize => i128
+);
+impl_difference_sub!(f32, f64, Vec2, Vec3, Vec3A, Vec4, DVec2, DVec3, DVec4);
+
+impl Difference for u128 {
+    type Output = i128;
+
+    #[inline]
+    fn difference(self, rhs: Self) -> Self::Output {
+        if self >= rhs {
+            i128::try_from(self - rhs).unwrap_or(i128::MAX)
+        } else {
+            i128::try_from(rhs - self).map_or(i128::MIN, |difference| -difference)
+        }
+    }
+}
+
+impl Difference for i128 {
+    type Output = i128;
+
+    #[inline]
+    fn difference(self, rhs: Self) -> Self::Output {
+        self.saturating_sub(rhs)
+    }
+}
+
+/// Returns the sum of two dereferenced components, `A + B`
+///
+/// This uses `+` directly, so integer overflow panics in debug builds and wraps in release builds.
+/// Use a wider type, such as `u32` instead of `u8`, if the sum can exceed the type's maximum.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct BaseArmor(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct BonusArmor(u32);
+///
+/// fn example(query: Query<SumOf<(BaseArmor, BonusArmor)>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced types must be addable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct BaseArmor(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct BonusArmor(f32);
+///
+/// fn bad_example(query: Query<SumOf<(BaseArmor, BonusArmor)>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type SumOf<T> = ModQ<SumOfQ<T>>;
+impl<A: Component + Deref, B: Component + Deref> ModQuery for SumOfQ<(A, B)>
+where
+    <A as Deref>::Target: Copy + Add<<B as Deref>::Target>,
+    <B as Deref>::Target: Copy,
+{
+    type FromQuery = (&'static A, &'static B);
+    type ModItem<'a> = <<A as Deref>::Target as Add<<B as Deref>::Target>>::Output;
+
+    fn modify_reference((a, b): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        *a.deref() + *b.deref()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the difference of two dereferenced components, `A - B`
+///
+/// Both components must dereference to the same [`Difference`] type. Integers are widened to a
+/// signed type, so this never panics on underflow: for `u32` components the result is an `i64`,
+/// which is negative when `B` is larger than `A`.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct MaxHealth(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct Health(u32);
+///
+/// // Missing health, i.e. `MaxHealth - Health`, which is negative when overhealed
+/// fn example(query: Query<DiffOf<(MaxHealth, Health)>>) {
+///     let _: i64 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let hurt = world.spawn((MaxHealth(100), Health(60))).id();
+/// # let overhealed = world.spawn((MaxHealth(100), Health(120))).id();
+/// # let mut query = world.query::<DiffOf<(MaxHealth, Health)>>();
+/// # assert_eq!(query.get(&world, hurt).unwrap(), 40);
+/// # assert_eq!(query.get(&world, overhealed).unwrap(), -20);
+/// # #[derive(Component, Deref)]
+/// # struct Big(u128);
+/// # #[derive(Component, Deref)]
+/// # struct Small(u128);
+/// # let under = world.spawn((Big(0), Small(u128::MAX))).id();
+/// # let mut query = world.query::<DiffOf<(Big, Small)>>();
+/// # assert_eq!(query.get(&world, under).unwrap(), i128::MIN);
+/// ```
+/// ## Counter Example: Dereferenced types must be subtractable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct MaxHealth(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct Health(f32);
+///
+/// fn bad_example(query: Query<DiffOf<(MaxHealth, Health)>>) {
+///     let _: i64 = query.get_single().unwrap();
+/// }
+/// ```
+pub type DiffOf<T> = ModQ<DiffOfQ<T>>;
+impl<V: Difference, A: Component + Deref<Target = V>, B: Component + Deref<Target = V>> ModQuery
+    for DiffOfQ<(A, B)>
+{
+    type FromQuery = (&'static A, &'static B);
+    type ModItem<'a> = V::Output;
+
+    fn modify_reference((a, b): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        a.deref().difference(*b.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the product of two dereferenced components, `A * B`
+///
+/// This uses `*` directly, so integer overflow panics in debug builds and wraps in release builds.
+/// Use a wider type, such as `u32` instead of `u8`, if the product can exceed the type's maximum.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Damage(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct DamageMultiplier(f32);
+///
+/// fn example(query: Query<ProductOf<(Damage, DamageMultiplier)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced types must be multipliable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Damage(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct DamageMultiplier(u8);
+///
+/// fn bad_example(query: Query<ProductOf<(Damage, DamageMultiplier)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ProductOf<T> = ModQ<ProductOfQ<T>>;
+impl<A: Component + Deref, B: Component + Deref> ModQuery for ProductOfQ<(A, B)>
+where
+    <A as Deref>::Target: Copy + Mul<<B as Deref>::Target>,
+    <B as Deref>::Target: Copy,
+{
+    type FromQuery = (&'static A, &'static B);
+    type ModItem<'a> = <<A as Deref>::Target as Mul<<B as Deref>::Target>>::Output;
+
+    fn modify_reference((a, b): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        *a.deref() * *b.deref()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the arithmetic mean of several components that all dereference to the same type
+///
+/// Implemented for tuples of 2 to 8 components of any [`Average`] type. The mean is computed
+/// without overflowing, even when every value is close to the type's maximum. For integer types
+/// the result is truncated towards zero, as with regular integer division.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Strength(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct Agility(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct Intellect(f32);
+///
+/// fn example(query: Query<AverageOf<(Strength, Agility, Intellect)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn((Strength(3.0), Agility(6.0), Intellect(9.0)));
+/// # let mut query = world.query::<AverageOf<(Strength, Agility, Intellect)>>();
+/// # assert_eq!(query.single(&world), 6.0);
+/// # #[derive(Component, Deref)]
+/// # struct Left(u8);
+/// # #[derive(Component, Deref)]
+/// # struct Right(u8);
+/// # let near_max = world.spawn((Left(200), Right(255))).id();
+/// # let mut query = world.query::<AverageOf<(Left, Right)>>();
+/// # assert_eq!(query.get(&world, near_max).unwrap(), 227);
+/// # #[derive(Component, Deref)]
+/// # struct Low(i8);
+/// # #[derive(Component, Deref)]
+/// # struct High(i8);
+/// # let mixed = world.spawn((Low(-128), High(-127))).id();
+/// # let rounded = world.spawn((Low(1), High(-4))).id();
+/// # let mut query = world.query::<AverageOf<(Low, High)>>();
+/// # assert_eq!(query.get(&world, mixed).unwrap(), -127);
+/// # assert_eq!(query.get(&world, rounded).unwrap(), -1);
+/// ```
+/// ## Counter Example: Components must all dereference to the same type
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Strength(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct Agility(u32);
+///
+/// fn bad_example(query: Query<AverageOf<(Strength, Agility)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AverageOf<T> = ModQ<AverageOfQ<T>>;
+
+macro_rules! impl_average_of {
+    ($($T:ident),*) => {
+        impl<V, $($T: Component + Deref<Target = V>),*> ModQuery for AverageOfQ<($($T,)*)>
+        where
+            V: Average,
+        {
+            type FromQuery = ($(&'static $T,)*);
+            type ModItem<'a> = V;
+
+            #[allow(non_snake_case)]
+            fn modify_reference(($($T,)*): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+                V::average(&[$(*$T.deref()),*])
+            }
+
+            fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+                item
+            }
+        }
+    };
+}
+
+all_tuples!(impl_average_of, 2, 8, T);
+
+/// Returns the ratio of two dereferenced components, `A / B`, as an `f32`
+///
+/// If the denominator is zero, this returns `0.0` instead of infinity or NaN. See [`RatioOfF64`]
+/// for an `f64` version.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct MaxHealth(u32);
+///
+/// fn example(query: Query<RatioOf<(Health, MaxHealth)>>) {
+///     let _health_percent: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let half = world.spawn((Health(5), MaxHealth(10))).id();
+/// # let zero = world.spawn((Health(5), MaxHealth(0))).id();
+/// # let mut query = world.query::<RatioOf<(Health, MaxHealth)>>();
+/// # assert_eq!(query.get(&world, half).unwrap(), 0.5);
+/// # assert_eq!(query.get(&world, zero).unwrap(), 0.0);
+/// ```
+/// ## Counter Example: Dereferenced types must be primitive numbers
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct MaxHealth(Vec<u32>);
+///
+/// fn bad_example(query: Query<RatioOf<(Health, MaxHealth)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type RatioOf<T> = ModQ<RatioOfQ<T>>;
+impl<A: Component + Deref, B: Component + Deref> ModQuery for RatioOfQ<(A, B)>
+where
+    <A as Deref>::Target: AsFloat,
+    <B as Deref>::Target: AsFloat,
+{
+    type FromQuery = (&'static A, &'static B);
+    type ModItem<'a> = f32;
+
+    fn modify_reference((a, b): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        let denominator = b.deref().as_f32();
+        if denominator == 0.0 {
+            0.0
+        } else {
+            a.deref().as_f32() / denominator
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the ratio of two dereferenced components, `A / B`, as an `f64`
+///
+/// If the denominator is zero, this returns `0.0` instead of infinity or NaN. See [`RatioOf`]
+/// for an `f32` version.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct BytesDownloaded(u64);
+///
+/// #[derive(Component, Deref)]
+/// struct BytesTotal(u64);
+///
+/// fn example(query: Query<RatioOfF64<(BytesDownloaded, BytesTotal)>>) {
+///     let _progress: f64 = query.get_single().unwrap();
+/// }
+/// ```
+pub type RatioOfF64<T> = ModQ<RatioOfF64Q<T>>;
+impl<A: Component + Deref, B: Component + Deref> ModQuery for RatioOfF64Q<(A, B)>
+where
+    <A as Deref>::Target: AsFloat,
+    <B as Deref>::Target: AsFloat,
+{
+    type FromQuery = (&'static A, &'static B);
+    type ModItem<'a> = f64;
+
+    fn modify_reference((a, b): <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        let denominator = b.deref().as_f64();
+        if denominator == 0.0 {
+            0.0
+        } else {
+            a.deref().as_f64() / denominator
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of component `V` clamped between the dereferenced values of
+/// components `Min` and `Max`, which must all dereference to the same type.
+///
+/// Unlike [`Ord::clamp`], this does not panic if `Min` is greater than `Max`. In that case the
+/// value of `Min` is returned.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Speed(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct MinSpeed(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct MaxSpeed(f32);
+///
+/// fn example(query: Query<ClampedByComponents<(Speed, MinSpeed, MaxSpeed)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let too_fast = world.spawn((Speed(12.0), MinSpeed(1.0), MaxSpeed(10.0))).id();
+/// # let too_slow = world.spawn((Speed(0.0), MinSpeed(1.0), MaxSpeed(10.0))).id();
+/// # let backwards = world.spawn((Speed(5.0), MinSpeed(10.0), MaxSpeed(1.0))).id();
+/// # let mut query = world.query::<ClampedByComponents<(Speed, MinSpeed, MaxSpeed)>>();
+/// # assert_eq!(query.get(&world, too_fast).unwrap(), 10.0);
+/// # assert_eq!(query.get(&world, too_slow).unwrap(), 1.0);
+/// # assert_eq!(query.get(&world, backwards).unwrap(), 10.0);
+/// ```
+/// ## Counter Example: Components must all dereference to the same type
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Speed(f32);
+///
+/// #[derive(Component, Deref)]
+/// struct MinSpeed(u32);
+///
+/// #[derive(Component, Deref)]
+/// struct MaxSpeed(f32);
+///
+/// fn bad_example(query: Query<ClampedByComponents<(Speed, MinSpeed, MaxSpeed)>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ClampedByComponents<T> = ModQ<ClampedByComponentsQ<T>>;
+impl<V, T, Min, Max> ModQuery for ClampedByComponentsQ<(T, Min, Max)>
+where
+    T: Component + Deref<Target = V>,
+    Min: Component + Deref<Target = V>,
+    Max: Component + Deref<Target = V>,
+    V: Copy + PartialOrd,
+{
+    type FromQuery = (&'static T, &'static Min, &'static Max);
+    type ModItem<'a> = V;
+
+    fn modify_reference(
+        (value, min, max): <Self::FromQuery as WorldQuery>::Item<'_>,
+    ) -> Self::ModItem<'_> {
+        let value = if *value.deref() > *max.deref() {
+            *max.deref()
+        } else {
+            *value.deref()
+        };
+        if value < *min.deref() {
+            *min.deref()
+        } else {
+            value
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced integer value of a component multiplied by the constant ratio
+/// `NUM / DEN`.
+///
+/// The result is exact before being truncated toward zero (as with normal integer division), and
+/// no intermediate value overflows, even for `i128`. If the result does not fit in the
+/// dereferenced type, it saturates to that type's minimum or maximum value. `DEN` must not be 0.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Centipoints(u32);
+///
+/// type Points = ScaledBy<Centipoints, 1, 100>;
+///
+/// fn example(query: Query<Points>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Centipoints(1250));
+/// # let mut query = world.query::<Points>();
+/// # assert_eq!(query.single(&world), 12);
+/// # #[derive(Component, Deref)]
+/// # struct Huge(i128);
+/// # let half = world.spawn(Huge(i128::MAX)).id();
+/// # let doubled = world.spawn(Huge(i128::MAX - 1)).id();
+/// # let negated = world.spawn(Huge(i128::MIN)).id();
+/// # let mut query = world.query::<ScaledBy<Huge, 3, 6>>();
+/// # assert_eq!(query.get(&world, half).unwrap(), i128::MAX / 2);
+/// # let mut query = world.query::<ScaledBy<Huge, 2, 1>>();
+/// # assert_eq!(query.get(&world, doubled).unwrap(), i128::MAX);
+/// # let mut query = world.query::<ScaledBy<Huge, 1, -1>>();
+/// # assert_eq!(query.get(&world, negated).unwrap(), i128::MAX);
+/// ```
+/// ## Counter Example: Dereferenced type must be an integer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Centipoints(f32);
+///
+/// fn bad_example(query: Query<ScaledBy<Centipoints, 1, 100>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Denominator must not be 0
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Centipoints(u32);
+///
+/// fn bad_example(query: Query<ScaledBy<Centipoints, 1, 0>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// # let mut world = World::new();
+/// # world.spawn(Centipoints(1250));
+/// # world.query::<ScaledBy<Centipoints, 1, 0>>().single(&world);
+/// ```
+pub type ScaledBy<T, const NUM: i64, const DEN: i64> = ModQ<ScaledByQ<T, NUM, DEN>>;
+impl<T: Component + Deref, const NUM: i64, const DEN: i64> ModQuery for ScaledByQ<T, NUM, DEN>
+where
+    <T as Deref>::Target: Integer,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        const { assert!(DEN != 0, "ScaledBy denominator must not be 0") };
+        // With a positive denominator, value * NUM / DEN splits into a whole part that only
+        // overflows if the result does, plus a fraction that can never overflow
+        let (num, den) = if DEN < 0 {
+            (-(NUM as i128), -(DEN as i128))
+        } else {
+            (NUM as i128, DEN as i128)
+        };
+        let value = t.deref().to_i128();
+        let whole = (value / den).saturating_mul(num);
+        let fraction = value % den * num / den;
+        Integer::saturating_from_i128(whole.saturating_add(fraction))
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced integer value of a component clamped to the inclusive range
+/// `MIN..=MAX`.
+///
+/// Useful for defensive reads of values that might be out of range, such as ones read from the
+/// network or save files. If `MIN` or `MAX` is outside the range of the dereferenced type, that
+/// bound saturates to the type's own minimum or maximum. `MIN` must not be greater than `MAX`.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Volume(i32);
+///
+/// fn example(query: Query<ClampedTo<Volume, 0, 100>>) {
+///     let _: i32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let loud = world.spawn(Volume(9000)).id();
+/// # let quiet = world.spawn(Volume(-3)).id();
+/// # let mut query = world.query::<ClampedTo<Volume, 0, 100>>();
+/// # assert_eq!(query.get(&world, loud).unwrap(), 100);
+/// # assert_eq!(query.get(&world, quiet).unwrap(), 0);
+/// ```
+/// ## Counter Example: Dereferenced type must be an integer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Volume(f32);
+///
+/// fn bad_example(query: Query<ClampedTo<Volume, 0, 100>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ClampedTo<T, const MIN: i64, const MAX: i64> = ModQ<ClampedToQ<T, MIN, MAX>>;
+impl<T: Component + Deref, const MIN: i64, const MAX: i64> ModQuery for ClampedToQ<T, MIN, MAX>
+where
+    <T as Deref>::Target: Integer,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        const { assert!(MIN <= MAX, "ClampedTo MIN must not be greater than MAX") };
+        Integer::saturating_from_i128(t.deref().to_i128().clamp(MIN as i128, MAX as i128))
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced integer value of a component cast to integer type `U`, saturating
+/// to `U`'s minimum or maximum value if it does not fit.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Health(u32);
+///
+/// fn example(query: Query<SaturatingCastTo<Health, u8>>) {
+///     let _: u8 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let small = world.spawn(Health(12)).id();
+/// # let large = world.spawn(Health(300)).id();
+/// # let mut query = world.query::<SaturatingCastTo<Health, u8>>();
+/// # assert_eq!(query.get(&world, small).unwrap(), 12);
+/// # assert_eq!(query.get(&world, large).unwrap(), 255);
+/// ```
+/// ## Counter Example: Only works for integer types
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Health(u32);
+///
+/// fn bad_example(query: Query<SaturatingCastTo<Health, f32>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type SaturatingCastTo<T, U> = ModQ<SaturatingCastToQ<T, U>>;
+impl<T: Component + Deref, U: Integer> ModQuery for SaturatingCastToQ<T, U>
+where
+    <T as Deref>::Target: Integer,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = U;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        U::saturating_from_i128(t.deref().to_i128())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component losslessly converted to an `f32`
+///
+/// Only types that convert to `f32` without losing precision are supported (such as `u16` or
+/// `i16`). For types like `u32`, see [`AsF64`].
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Level(u16);
+///
+/// fn example(query: Query<AsF32<Level>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Conversion must be lossless
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Score(u32);
+///
+/// fn bad_example(query: Query<AsF32<Score>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsF32<T> = ModQ<AsF32Q<T>>;
+impl<T: Component + Deref> ModQuery for AsF32Q<T>
+where
+    <T as Deref>::Target: Copy + Into<f32>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        (*t.deref()).into()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component losslessly converted to an `f64`
+///
+/// Only types that convert to `f64` without losing precision are supported (such as `u32`,
+/// `i32` or `f32`).
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Score(u32);
+///
+/// fn example(query: Query<AsF64<Score>>) {
+///     let _: f64 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Conversion must be lossless
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Score(u64);
+///
+/// fn bad_example(query: Query<AsF64<Score>>) {
+///     let _: f64 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsF64<T> = ModQ<AsF64Q<T>>;
+impl<T: Component + Deref> ModQuery for AsF64Q<T>
+where
+    <T as Deref>::Target: Copy + Into<f64>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = f64;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        (*t.deref()).into()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the absolute value of the dereferenced value of a component
+///
+/// For integers, the absolute value of `MIN` saturates to `MAX`.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct HorizontalVelocity(f32);
+///
+/// fn example(query: Query<AbsOf<HorizontalVelocity>>) {
+///     let _speed: f32 = query.get_single().unwrap();
+/// }
+///
+/// # #[derive(Component, Deref)]
+/// # struct Offset(i8);
+/// # let mut world = World::new();
+/// # world.spawn(Offset(i8::MIN));
+/// # let mut query = world.query::<AbsOf<Offset>>();
+/// # assert_eq!(query.single(&world), i8::MAX);
+/// ```
+/// ## Counter Example: Dereferenced type must be signed
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Speed(u32);
+///
+/// fn bad_example(query: Query<AbsOf<Speed>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AbsOf<T> = ModQ<AbsOfQ<T>>;
+impl<T: Component + Deref> ModQuery for AbsOfQ<T>
+where
+    <T as Deref>::Target: Signed,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Signed::abs(*t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the sign of the dereferenced value of a component
+///
+/// This follows the `signum` method of the dereferenced type: for integers this is `-1`, `0` or
+/// `1`, while for floats it is `-1.0` or `1.0` (including for `-0.0` and `0.0` respectively).
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct HorizontalVelocity(i32);
+///
+/// fn example(query: Query<SignumOf<HorizontalVelocity>>) {
+///     let _facing: i32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let left = world.spawn(HorizontalVelocity(-12)).id();
+/// # let still = world.spawn(HorizontalVelocity(0)).id();
+/// # let mut query = world.query::<SignumOf<HorizontalVelocity>>();
+/// # assert_eq!(query.get(&world, left).unwrap(), -1);
+/// # assert_eq!(query.get(&world, still).unwrap(), 0);
+/// ```
+/// ## Counter Example: Dereferenced type must be signed
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Speed(u32);
+///
+/// fn bad_example(query: Query<SignumOf<Speed>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type SignumOf<T> = ModQ<SignumOfQ<T>>;
+impl<T: Component + Deref> ModQuery for SignumOfQ<T>
+where
+    <T as Deref>::Target: Signed,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Signed::signum(*t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced `u8` value of a component mapped from `0..=255` to `0.0..=1.0`
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Alpha(u8);
+///
+/// fn example(query: Query<NormalizedU8<Alpha>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let opaque = world.spawn(Alpha(255)).id();
+/// # let transparent = world.spawn(Alpha(0)).id();
+/// # let mut query = world.query::<NormalizedU8<Alpha>>();
+/// # assert_eq!(query.get(&world, opaque).unwrap(), 1.0);
+/// # assert_eq!(query.get(&world, transparent).unwrap(), 0.0);
+/// ```
+/// ## Counter Example: Dereferenced type must be u8
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Alpha(u16);
+///
+/// fn bad_example(query: Query<NormalizedU8<Alpha>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type NormalizedU8<T> = ModQ<NormalizedU8Q<T>>;
+impl<T: Component + Deref<Target = u8>> ModQuery for NormalizedU8Q<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        f32::from(*t.deref()) / 255.0
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component quantized to a `BITS`-bit unsigned integer
+/// within the range provided by `R`, for compact network replication.
+///
+/// The start of the range maps to 0 and the end of the range maps to `2^BITS - 1`, rounding to
+/// the nearest step. Values outside of the range are clamped to it, and if the range is empty
+/// this always returns 0. `BITS` must be between 1 and 32.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// use std::ops::RangeInclusive;
+///
+/// #[derive(Component, Deref)]
+/// struct Heading(f32);
+///
+/// struct HeadingRange;
+///
+/// impl Provider<RangeInclusive<f32>> for HeadingRange {
+///     const VALUE: RangeInclusive<f32> = 0.0..=360.0;
+/// }
+///
+/// fn example(query: Query<Quantized<Heading, HeadingRange, 8>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let start = world.spawn(Heading(0.0)).id();
+/// # let end = world.spawn(Heading(360.0)).id();
+/// # let middle = world.spawn(Heading(180.0)).id();
+/// # let out_of_range = world.spawn(Heading(-20.0)).id();
+/// # let mut query = world.query::<Quantized<Heading, HeadingRange, 8>>();
+/// # assert_eq!(query.get(&world, start).unwrap(), 0);
+/// # assert_eq!(query.get(&world, end).unwrap(), 255);
+/// # assert_eq!(query.get(&world, middle).unwrap(), 128);
+/// # assert_eq!(query.get(&world, out_of_range).unwrap(), 0);
+/// ```
+/// ## Counter Example: Range must be provided
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Heading(f32);
+///
+/// struct HeadingRange;
+///
+/// fn bad_example(query: Query<Quantized<Heading, HeadingRange, 8>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type Quantized<T, R, const BITS: u8> = ModQ<QuantizedQ<T, R, BITS>>;
+impl<T: Component + Deref, R: Provider<RangeInclusive<f32>>, const BITS: u8> ModQuery
+    for QuantizedQ<T, R, BITS>
+where
+    <T as Deref>::Target: Copy + Into<f64>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = u32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        const {
+            assert!(
+                BITS >= 1 && BITS <= 32,
+                "Quantized BITS must be between 1 and 32"
+            )
+        };
+        let min = f64::from(*R::VALUE.start());
+        let max = f64::from(*R::VALUE.end());
+        let steps = ((1u64 << BITS) - 1) as f64;
+        let value: f64 = (*t.deref()).into();
+        let normalized = if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (normalized * steps).round() as u32
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component storing an angle in radians, converted to
+/// degrees
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct FacingRadians(f32);
+///
+/// fn example(query: Query<AsDegrees<FacingRadians>>) {
+///     let _facing_degrees: f32 = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced type must be a float
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct FacingRadians(u32);
+///
+/// fn bad_example(query: Query<AsDegrees<FacingRadians>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsDegrees<T> = ModQ<AsDegreesQ<T>>;
+impl<T: Component + Deref> ModQuery for AsDegreesQ<T>
+where
+    <T as Deref>::Target: Float,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Float::to_degrees(*t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component storing an angle in degrees, converted to
+/// radians
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct FieldOfViewDegrees(f32);
+///
+/// fn example(query: Query<AsRadians<FieldOfViewDegrees>>) {
+///     let _fov_radians: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsRadians<T> = ModQ<AsRadiansQ<T>>;
+impl<T: Component + Deref> ModQuery for AsRadiansQ<T>
+where
+    <T as Deref>::Target: Float,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Float::to_radians(*t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced value of a component storing an angle in radians, wrapped to the
+/// range `[0, 2π)`
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::f32::consts::PI;
+/// #[derive(Component, Deref)]
+/// struct FacingRadians(f32);
+///
+/// fn example(query: Query<WrappedRadians<FacingRadians>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(FacingRadians(-PI / 2.0));
+/// # let mut query = world.query::<WrappedRadians<FacingRadians>>();
+/// # assert_eq!(query.single(&world), 3.0 * PI / 2.0);
+/// # world.spawn(FacingRadians(-f32::EPSILON / 1024.0));
+/// # assert!(query.iter(&world).all(|angle| (0.0..2.0 * PI).contains(&angle)));
+/// ```
+pub type WrappedRadians<T> = ModQ<WrappedRadiansQ<T>>;
+impl<T: Component + Deref> ModQuery for WrappedRadiansQ<T>
+where
+    <T as Deref>::Target: Float,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().wrap_radians()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the dereferenced float value of a component rounded to `DECIMALS` decimal places
+///
+/// Halfway cases are rounded away from zero. Since most decimal fractions can't be represented
+/// exactly in floating point, the result is the closest float to the rounded value.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Temperature(f32);
+///
+/// fn example(query: Query<RoundedTo<Temperature, 1>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Temperature(21.4321));
+/// # let mut query = world.query::<RoundedTo<Temperature, 1>>();
+/// # assert_eq!(query.single(&world), 21.4);
+/// ```
+/// ## Counter Example: Dereferenced type must be a float
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Temperature(i32);
+///
+/// fn bad_example(query: Query<RoundedTo<Temperature, 1>>) {
+///     let _: i32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type RoundedTo<T, const DECIMALS: u32> = ModQ<RoundedToQ<T, DECIMALS>>;
+impl<T: Component + Deref, const DECIMALS: u32> ModQuery for RoundedToQ<T, DECIMALS>
+where
+    <T as Deref>::Target: Float,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().round_to_decimals(DECIMALS)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns `true` if the dereferenced integer value of a component is within the inclusive range
+/// `MIN..=MAX`
+///
+/// Unlike a filter, every entity with the component is still visited, so systems can branch on
+/// this per entity.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Level(u32);
+///
+/// type IsBeginner = InRangeFlag<Level, 1, 10>;
+///
+/// fn example(query: Query<IsBeginner>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let low = world.spawn(Level(10)).id();
+/// # let high = world.spawn(Level(11)).id();
+/// # let mut query = world.query::<IsBeginner>();
+/// # assert!(query.get(&world, low).unwrap());
+/// # assert!(!query.get(&world, high).unwrap());
+/// ```
+/// ## Counter Example: Dereferenced type must be an integer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Level(f32);
+///
+/// fn bad_example(query: Query<InRangeFlag<Level, 1, 10>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type InRangeFlag<T, const MIN: i64, const MAX: i64> = ModQ<InRangeFlagQ<T, MIN, MAX>>;
+impl<T: Component + Deref, const MIN: i64, const MAX: i64> ModQuery for InRangeFlagQ<T, MIN, MAX>
+where
+    <T as Deref>::Target: Integer,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        (MIN as i128..=MAX as i128).contains(&t.deref().to_i128())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,453 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::Deref;
+#[cfg(feature = "bevy_time")]
+use std::ops::Div;
+
+use bevy::app::{App, Last, Plugin};
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::event::{Event, EventWriter};
+#[cfg(feature = "bevy_time")]
+use bevy::ecs::query::QueryFilter;
+use bevy::ecs::query::{Changed, WorldQuery};
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::{Query, Resource};
+#[cfg(feature = "bevy_time")]
+use bevy::ecs::system::{Res, SystemParam};
+#[cfg(feature = "bevy_time")]
+use bevy::time::Time;
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+use super::numeric::Difference;
+
+/// A plugin that records the value of component `T` at the end of every frame, for use with
+/// [`Prev`]
+///
+/// The value is stored in a [`Previous<T>`] component, which is inserted and removed along with
+/// `T` using commands. When `T` is first added, its previous value is the same as its current one.
+///
+/// Other plugins such as [`ValueChangedPlugin<T>`] add this plugin themselves, so adding it more
+/// than once is allowed and has no further effect.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Deref)]
+/// struct Health(f32);
+///
+/// App::new().add_plugins(PreviousPlugin::<Health>::default());
+///
+/// // Adding it again, directly or through another plugin, is fine
+/// App::new().add_plugins((
+///     ValueChangedPlugin::<Health>::default(),
+///     PreviousPlugin::<Health>::default(),
+/// ));
+/// ```
+pub struct PreviousPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for PreviousPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Debug for PreviousPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PreviousPlugin").finish()
+    }
+}
+
+impl<T: Component + Clone> Plugin for PreviousPlugin<T> {
+    fn build(&self, app: &mut App) {
+        if app.world().contains_resource::<PreviousPluginAdded<T>>() {
+            return;
+        }
+        app.insert_resource(PreviousPluginAdded::<T>(PhantomData));
+        add_companion::<T, Previous<T>>(app).add_systems(Last, record_previous::<T>);
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+/// Marks that [`PreviousPlugin<T>`] has already been built, since it isn't unique
+#[derive(Resource)]
+struct PreviousPluginAdded<T>(PhantomData<fn() -> T>);
+
+/// The value component `T` had at the end of the last frame, recorded by [`PreviousPlugin<T>`]
+///
+/// Use [`Prev<T>`] to read it in queries.
+#[derive(Component, Debug)]
+pub struct Previous<T: Component + Clone>(T);
+
+impl<T: Component + Clone> Companion<T> for Previous<T> {
+    fn new(value: &T) -> Self {
+        Self(value.clone())
+    }
+}
+
+fn record_previous<T: Component + Clone>(mut query: Query<(&T, &mut Previous<T>), Changed<T>>) {
+    for (value, mut previous) in query.iter_mut() {
+        previous.0.clone_from(value);
+    }
+}
+
+#[derive(Debug)]
+pub struct PrevQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct DeltaQ<T>(PhantomData<T>);
+
+/// Returns the value component `T` had at the end of the last frame
+///
+/// Requires [`PreviousPlugin<T>`] to be added to the app. If `T` was added this frame, this starts
+/// out the same as its current value, once commands are applied.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Health(f32);
+///
+/// fn example(query: Query<(&Health, Prev<Health>)>) {
+///     for (health, prev_health) in query.iter() {
+///         if health.0 < prev_health.0 {
+///             println!("Ouch!");
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(PreviousPlugin::<Health>::default());
+/// # let entity = app.world_mut().spawn(Health(10.0)).id();
+/// # app.update();
+/// # app.world_mut().get_mut::<Health>(entity).unwrap().0 = 7.0;
+/// # let mut query = app.world_mut().query::<Prev<Health>>();
+/// # assert_eq!(query.single(app.world()).0, 10.0);
+/// # app.update();
+/// # assert_eq!(query.single(app.world()).0, 7.0);
+/// # app.world_mut().entity_mut(entity).remove::<Health>();
+/// # app.update();
+/// # assert!(query.get_single(app.world()).is_err());
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn bad_example(query: Query<Prev<Health>>) {
+///     let _: &Health = query.get_single().unwrap();
+/// }
+/// ```
+pub type Prev<T> = ModQ<PrevQ<T>>;
+impl<T: Component + Clone> ModQuery for PrevQ<T> {
+    type FromQuery = &'static Previous<T>;
+    type ModItem<'a> = &'a T;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        &t.0
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns how much a dereferenced component changed since the end of the last frame
+///
+/// That is, `current - previous`, computed with [`Difference`] so it never underflows: for
+/// unsigned integers the result is a wider signed integer, which is negative when the value went
+/// down. Requires [`PreviousPlugin<T>`] to be added to the app, like [`Prev<T>`].
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Deref)]
+/// struct Health(f32);
+///
+/// fn example(query: Query<Delta<Health>>) {
+///     for delta in query.iter() {
+///         if delta < 0.0 {
+///             println!("Took {} damage!", -delta);
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(PreviousPlugin::<Health>::default());
+/// # let entity = app.world_mut().spawn(Health(10.0)).id();
+/// # app.update();
+/// # let mut query = app.world_mut().query::<Delta<Health>>();
+/// # assert_eq!(query.single(app.world()), 0.0);
+/// # app.world_mut().get_mut::<Health>(entity).unwrap().0 = 7.0;
+/// # assert_eq!(query.single(app.world()), -3.0);
+/// # app.update();
+/// # assert_eq!(query.single(app.world()), 0.0);
+/// # #[derive(Component, Clone, Deref)]
+/// # struct Lives(u32);
+/// # app.add_plugins(PreviousPlugin::<Lives>::default());
+/// # let entity = app.world_mut().spawn(Lives(3)).id();
+/// # app.update();
+/// # app.world_mut().get_mut::<Lives>(entity).unwrap().0 = 1;
+/// # let mut query = app.world_mut().query::<Delta<Lives>>();
+/// # assert_eq!(query.single(app.world()), -2i64);
+/// ```
+/// ## Counter Example: Dereferenced type must be subtractable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Deref)]
+/// struct Status(bool);
+///
+/// fn bad_example(query: Query<Delta<Status>>) {
+///     let _ = query.get_single().unwrap();
+/// }
+/// ```
+pub type Delta<T> = ModQ<DeltaQ<T>>;
+impl<T: Component + Clone + Deref> ModQuery for DeltaQ<T>
+where
+    <T as Deref>::Target: Difference,
+{
+    type FromQuery = (&'static T, &'static Previous<T>);
+    type ModItem<'a> = <<T as Deref>::Target as Difference>::Output;
+
+    fn modify_reference(
+        (current, previous): <Self::FromQuery as WorldQuery>::Item<'_>,
+    ) -> Self::ModItem<'_> {
+        current.deref().difference(*previous.0.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// A [`SystemParam`] for how fast dereferenced components are changing per second, using
+/// [`Delta<T>`] and the [`Time`] resource
+///
+/// That is, `(current - previous) / delta_secs`, for the entities matching the filter `F`. This
+/// needs some time to have passed, so nothing is returned on the first frame. Requires
+/// [`PreviousPlugin<T>`] to be added to the app, like [`Prev<T>`].
+///
+/// Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// # use std::time::Duration;
+/// #[derive(Component, Clone, Deref)]
+/// struct Distance(f32);
+///
+/// fn example(speeds: RateOfChange<Distance>) {
+///     for (entity, speed) in speeds.iter() {
+///         println!("{entity} is moving at {speed} m/s");
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(PreviousPlugin::<Distance>::default()).init_resource::<Time>();
+/// # let entity = app.world_mut().spawn(Distance(10.0)).id();
+/// # app.update();
+/// # app.world_mut().get_mut::<Distance>(entity).unwrap().0 = 12.0;
+/// # app.world_mut().run_system_once(move |speeds: RateOfChange<Distance>| {
+/// #     assert_eq!(speeds.get(entity), None);
+/// # }).unwrap();
+/// # app.world_mut().resource_mut::<Time>().advance_by(Duration::from_millis(500));
+/// # app.world_mut().run_system_once(move |speeds: RateOfChange<Distance>| {
+/// #     assert_eq!(speeds.get(entity), Some(4.0));
+/// #     assert_eq!(speeds.iter().collect::<Vec<_>>(), [(entity, 4.0)]);
+/// # }).unwrap();
+/// ```
+/// ## Counter Example: Difference must be divisible by f32 (integers are not)
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Deref)]
+/// struct Distance(u32);
+///
+/// fn bad_example(speeds: RateOfChange<Distance>) {
+///     for (entity, speed) in speeds.iter() {
+///         println!("{entity} is moving at {speed} m/s");
+///     }
+/// }
+/// ```
+#[cfg(feature = "bevy_time")]
+#[derive(SystemParam)]
+pub struct RateOfChange<'w, 's, T, F = ()>
+where
+    T: Component + Clone + Deref<Target: Difference>,
+    F: QueryFilter + 'static,
+{
+    query: Query<'w, 's, (Entity, Delta<T>), F>,
+    time: Res<'w, Time>,
+}
+
+#[cfg(feature = "bevy_time")]
+impl<T, F> RateOfChange<'_, '_, T, F>
+where
+    T: Component + Clone + Deref<Target: Difference<Output: Div<f32>>>,
+    F: QueryFilter + 'static,
+{
+    /// Returns how fast the entity's dereferenced component is changing per second.
+    ///
+    /// Returns `None` if the entity doesn't match the query or no time has passed.
+    pub fn get(&self, entity: Entity) -> Option<RateOf<T>> {
+        let delta_secs = self.delta_secs()?;
+        let (_, delta) = self.query.get(entity).ok()?;
+        Some(delta / delta_secs)
+    }
+
+    /// Iterates over how fast each entity's dereferenced component is changing per second.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, RateOf<T>)> + '_ {
+        let delta_secs = self.delta_secs();
+        delta_secs.into_iter().flat_map(move |delta_secs| {
+            self.query
+                .iter()
+                .map(move |(entity, delta)| (entity, delta / delta_secs))
+        })
+    }
+
+    fn delta_secs(&self) -> Option<f32> {
+        Some(self.time.delta_secs()).filter(|&delta_secs| delta_secs > 0.0)
+    }
+}
+
+#[cfg(feature = "bevy_time")]
+impl<T, F> Debug for RateOfChange<'_, '_, T, F>
+where
+    T: Component + Clone + Deref<Target: Difference>,
+    F: QueryFilter + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateOfChange")
+            .field("delta_secs", &self.time.delta_secs())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The type [`RateOfChange<T>`] returns, the difference of `T`'s dereferenced values divided by
+/// an `f32`
+#[cfg(feature = "bevy_time")]
+pub type RateOf<T> = <<<T as Deref>::Target as Difference>::Output as Div<f32>>::Output;
+
+/// A plugin that sends a [`ValueChanged<T>`] event whenever the dereferenced value of component `T`
+/// is different from what it was at the end of the last frame
+///
+/// Unlike [`Changed<T>`], this ignores mutable access that doesn't actually change the value. The
+/// events are sent at the end of the frame, in [`Last`], so systems read them in the next frame.
+/// Adds [`PreviousPlugin<T>`] if it hasn't been added yet.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Deref, DerefMut)]
+/// struct Health(f32);
+///
+/// fn example(mut events: EventReader<ValueChanged<Health>>) {
+///     for event in events.read() {
+///         println!("{} health went from {} to {}", event.entity, event.old, event.new);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(ValueChangedPlugin::<Health>::default());
+/// # let entity = app.world_mut().spawn(Health(10.0)).id();
+/// # app.update();
+/// # **app.world_mut().get_mut::<Health>(entity).unwrap() = 10.0;
+/// # app.update();
+/// # let events = app.world().resource::<Events<ValueChanged<Health>>>();
+/// # assert!(events.iter_current_update_events().next().is_none());
+/// # **app.world_mut().get_mut::<Health>(entity).unwrap() = 7.0;
+/// # app.update();
+/// # let events = app.world().resource::<Events<ValueChanged<Health>>>();
+/// # let event = events.iter_current_update_events().next().unwrap();
+/// # assert_eq!((event.entity, event.old, event.new), (entity, 10.0, 7.0));
+/// ```
+/// ## Counter Example: Dereferenced type must be PartialEq
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Clone)]
+/// struct Stats;
+///
+/// #[derive(Component, Clone, Deref)]
+/// struct Wrapped(Stats);
+///
+/// App::new().add_plugins(ValueChangedPlugin::<Wrapped>::default());
+/// ```
+pub struct ValueChangedPlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for ValueChangedPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Debug for ValueChangedPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ValueChangedPlugin").finish()
+    }
+}
+
+impl<T> Plugin for ValueChangedPlugin<T>
+where
+    T: Component + Clone + Deref<Target: PartialEq + Clone + Send + Sync>,
+{
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PreviousPlugin::<T>::default())
+            .add_event::<ValueChanged<T>>()
+            .add_systems(Last, send_value_changed::<T>.before(record_previous::<T>));
+    }
+}
+
+/// An event sent by [`ValueChangedPlugin<T>`] when the dereferenced value of component `T` has
+/// changed since the end of the last frame
+#[derive(Event)]
+pub struct ValueChanged<T: Deref<Target: Sized + Send + Sync> + Send + Sync + 'static> {
+    /// The entity whose component changed.
+    pub entity: Entity,
+    /// The dereferenced value at the end of the last frame.
+    pub old: T::Target,
+    /// The dereferenced value at the end of this frame.
+    pub new: T::Target,
+}
+
+impl<T> Debug for ValueChanged<T>
+where
+    T: Deref<Target: Debug + Sized + Send + Sync> + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueChanged")
+            .field("entity", &self.entity)
+            .field("old", &self.old)
+            .field("new", &self.new)
+            .finish()
+    }
+}
+
+fn send_value_changed<T>(
+    query: Query<(Entity, &T, &Previous<T>), Changed<T>>,
+    mut events: EventWriter<ValueChanged<T>>,
+) where
+    T: Component + Clone + Deref<Target: PartialEq + Clone + Send + Sync>,
+{
+    for (entity, current, previous) in query.iter() {
+        if current.deref() != previous.0.deref() {
+            events.send(ValueChanged {
+                entity,
+                old: previous.0.deref().clone(),
+                new: current.deref().clone(),
+            });
+        }
+    }
+}
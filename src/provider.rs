@@ -0,0 +1,19 @@
+/// A type that provides a constant value of type `T`.
+///
+/// Const generics only support a few primitive types, so adapters that need other kinds of
+/// constant parameters (such as float ranges or strings) take a provider type instead.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// use std::ops::RangeInclusive;
+///
+/// struct WorldBounds;
+///
+/// impl Provider<RangeInclusive<f32>> for WorldBounds {
+///     const VALUE: RangeInclusive<f32> = -1000.0..=1000.0;
+/// }
+/// ```
+pub trait Provider<T> {
+    const VALUE: T;
+}
@@ -0,0 +1,319 @@
+use std::borrow::Borrow;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::{QueryEntityError, QueryFilter, QuerySingleError, QueryState};
+use bevy::ecs::system::Query;
+use bevy::ecs::world::{EntityMut, EntityRef, EntityWorldMut, World};
+
+use super::extensions::{AsDeref, AsDerefCloned, AsDerefCopied, Cloned, Copied};
+
+/// Methods on a [`Query`] for a single component that copy, clone or dereference its items, like
+/// [`Copied`](crate::Copied), [`Cloned`](crate::Cloned) and [`AsDeref`](crate::AsDeref) do in the
+/// query type
+///
+/// Each method has the same bounds as its adapter, so this is only a matter of whether you prefer
+/// method syntax or keeping it in the query type.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Clone, Copy, Deref)]
+/// struct Health(f32);
+///
+/// fn example(query: Query<&Health>, entity: Entity, targets: &[Entity]) {
+///     let _: Vec<Health> = query.iter_copied().collect();
+///     let _: Vec<&f32> = query.iter_deref().collect();
+///     let _: Health = query.get_copied(entity).unwrap();
+///     let _: [Health; 2] = query.get_many_copied([entity, targets[0]]).unwrap();
+///     let _: Vec<&f32> = query.iter_many_deref(targets).collect();
+/// }
+///
+/// # let mut world = World::new();
+/// # let entity = world.spawn(Health(3.0)).id();
+/// # let other = world.spawn(Health(5.0)).id();
+/// # world.run_system_once(move |query: Query<&Health>| {
+/// #     let [a, b] = query.get_many_copied([other, entity]).unwrap();
+/// #     assert_eq!((a.0, b.0), (5.0, 3.0));
+/// #     assert_eq!(query.iter_many_deref([entity, other]).collect::<Vec<_>>(), [&3.0, &5.0]);
+/// # }).unwrap();
+/// # world.entity_mut(other).despawn();
+/// # world.run_system_once(move |query: Query<&Health>| {
+/// #     assert_eq!(query.iter_copied().map(|h| h.0).collect::<Vec<_>>(), [3.0]);
+/// #     assert_eq!(query.iter_cloned().map(|h| h.0).collect::<Vec<_>>(), [3.0]);
+/// #     assert_eq!(query.iter_deref().collect::<Vec<_>>(), [&3.0]);
+/// #     assert_eq!(query.single_copied().unwrap().0, 3.0);
+/// #     assert_eq!(query.get_copied(entity).unwrap().0, 3.0);
+/// # }).unwrap();
+/// ```
+/// ## Counter Example: Component must be Copy
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Health(f32);
+///
+/// fn bad_example(query: Query<&Health>) {
+///     let _: Vec<Health> = query.iter_copied().collect();
+/// }
+/// ```
+pub trait QueryValuesExt<T> {
+    /// Iterates over copies of the query items.
+    fn iter_copied(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy;
+
+    /// Iterates over clones of the query items.
+    fn iter_cloned(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone;
+
+    /// Iterates over the dereferenced query items.
+    fn iter_deref<'a>(&'a self) -> impl Iterator<Item = &'a T::Target>
+    where
+        T: Deref<Target: 'a>;
+
+    /// Returns a copy of the only query item, like [`Query::get_single`].
+    fn single_copied(&self) -> Result<T, QuerySingleError>
+    where
+        T: Copy;
+
+    /// Returns a copy of the query item for `entity`, like [`Query::get`].
+    fn get_copied(&self, entity: Entity) -> Result<T, QueryEntityError<'_>>
+    where
+        T: Copy;
+
+    /// Returns copies of the query items for `entities`, like [`Query::get_many`].
+    fn get_many_copied<const N: usize>(
+        &self,
+        entities: [Entity; N],
+    ) -> Result<[T; N], QueryEntityError<'_>>
+    where
+        T: Copy;
+
+    /// Iterates over the dereferenced query items for `entities`, like [`Query::iter_many`].
+    fn iter_many_deref<'a, I>(&'a self, entities: I) -> impl Iterator<Item = &'a T::Target>
+    where
+        T: Deref<Target: 'a>,
+        I: IntoIterator<Item: Borrow<Entity>> + 'a;
+}
+
+impl<T: Component, F: QueryFilter> QueryValuesExt<T> for Query<'_, '_, &T, F> {
+    fn iter_copied(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Copy,
+    {
+        self.iter().copied()
+    }
+
+    fn iter_cloned(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        self.iter().cloned()
+    }
+
+    fn iter_deref<'a>(&'a self) -> impl Iterator<Item = &'a T::Target>
+    where
+        T: Deref<Target: 'a>,
+    {
+        self.iter().map(Deref::deref)
+    }
+
+    fn single_copied(&self) -> Result<T, QuerySingleError>
+    where
+        T: Copy,
+    {
+        self.get_single().copied()
+    }
+
+    fn get_copied(&self, entity: Entity) -> Result<T, QueryEntityError<'_>>
+    where
+        T: Copy,
+    {
+        self.get(entity).copied()
+    }
+
+    fn get_many_copied<const N: usize>(
+        &self,
+        entities: [Entity; N],
+    ) -> Result<[T; N], QueryEntityError<'_>>
+    where
+        T: Copy,
+    {
+        self.get_many(entities).map(|items| items.map(|item| *item))
+    }
+
+    fn iter_many_deref<'a, I>(&'a self, entities: I) -> impl Iterator<Item = &'a T::Target>
+    where
+        T: Deref<Target: 'a>,
+        I: IntoIterator<Item: Borrow<Entity>> + 'a,
+    {
+        self.iter_many(entities).map(Deref::deref)
+    }
+}
+
+/// Methods on [`World`] that build [`QueryState`]s for this crate's basic adapters, like
+/// [`World::query`]
+///
+/// Useful for exclusive systems and tests, which use the world directly instead of system params.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Copy, Deref)]
+/// struct Health(f32);
+///
+/// fn example(world: &mut World) {
+///     let _: Vec<Health> = world.query_copied::<Health>().iter(world).collect();
+///     let _: Vec<f32> = world.query_deref_copied::<Health>().iter(world).collect();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Health(3.0));
+/// # assert_eq!(world.query_copied::<Health>().single(&world).0, 3.0);
+/// # assert_eq!(world.query_cloned::<Health>().single(&world).0, 3.0);
+/// # assert_eq!(world.query_deref::<Health>().single(&world), &3.0);
+/// # assert_eq!(world.query_deref_copied::<Health>().single(&world), 3.0);
+/// # assert_eq!(world.query_deref_cloned::<Health>().single(&world), 3.0);
+/// ```
+/// ## Counter Example: Component must be Copy
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Health(f32);
+///
+/// fn bad_example(world: &mut World) {
+///     let _: Vec<Health> = world.query_copied::<Health>().iter(world).collect();
+/// }
+/// ```
+pub trait WorldQueryExt {
+    /// Returns a [`QueryState`] for [`Copied<T>`].
+    fn query_copied<T: Component + Copy>(&mut self) -> QueryState<Copied<T>>;
+
+    /// Returns a [`QueryState`] for [`Cloned<T>`].
+    fn query_cloned<T: Component + Clone>(&mut self) -> QueryState<Cloned<T>>;
+
+    /// Returns a [`QueryState`] for [`AsDeref<T>`].
+    fn query_deref<T: Component + Deref>(&mut self) -> QueryState<AsDeref<T>>;
+
+    /// Returns a [`QueryState`] for [`AsDerefCopied<T>`].
+    fn query_deref_copied<T>(&mut self) -> QueryState<AsDerefCopied<T>>
+    where
+        T: Component + Deref<Target: Copy>;
+
+    /// Returns a [`QueryState`] for [`AsDerefCloned<T>`].
+    fn query_deref_cloned<T>(&mut self) -> QueryState<AsDerefCloned<T>>
+    where
+        T: Component + Deref<Target: Clone>;
+}
+
+impl WorldQueryExt for World {
+    fn query_copied<T: Component + Copy>(&mut self) -> QueryState<Copied<T>> {
+        self.query()
+    }
+
+    fn query_cloned<T: Component + Clone>(&mut self) -> QueryState<Cloned<T>> {
+        self.query()
+    }
+
+    fn query_deref<T: Component + Deref>(&mut self) -> QueryState<AsDeref<T>> {
+        self.query()
+    }
+
+    fn query_deref_copied<T>(&mut self) -> QueryState<AsDerefCopied<T>>
+    where
+        T: Component + Deref<Target: Copy>,
+    {
+        self.query()
+    }
+
+    fn query_deref_cloned<T>(&mut self) -> QueryState<AsDerefCloned<T>>
+    where
+        T: Component + Deref<Target: Clone>,
+    {
+        self.query()
+    }
+}
+
+/// Methods on [`EntityRef`], [`EntityMut`] and [`EntityWorldMut`] that copy or dereference a
+/// component, like [`Copied`] and [`AsDeref`] do in queries
+///
+/// Useful for commands and observers, which work with entities instead of queries.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Copy, Deref)]
+/// struct Health(f32);
+///
+/// fn example(entity: EntityRef) {
+///     let _: Option<Health> = entity.get_copied::<Health>();
+///     let _: Option<&f32> = entity.get_deref::<Health>();
+///     let _: f32 = entity.get_deref_copied_or::<Health>(0.0);
+/// }
+///
+/// # let mut world = World::new();
+/// # let with = world.spawn(Health(3.0)).id();
+/// # let without = world.spawn_empty().id();
+/// # let entity = world.entity(with);
+/// # assert_eq!(entity.get_copied::<Health>().unwrap().0, 3.0);
+/// # assert_eq!(entity.get_deref::<Health>(), Some(&3.0));
+/// # assert_eq!(entity.get_deref_copied_or::<Health>(0.0), 3.0);
+/// # assert_eq!(world.entity_mut(without).get_deref_copied_or::<Health>(0.0), 0.0);
+/// # assert_eq!(world.entity_mut(without).get_deref::<Health>(), None);
+/// ```
+/// ## Counter Example: Component must be Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone, Copy)]
+/// struct Health(f32);
+///
+/// fn bad_example(entity: EntityRef) {
+///     let _: Option<&f32> = entity.get_deref::<Health>();
+/// }
+/// ```
+pub trait EntityValuesExt {
+    /// Returns a copy of the entity's component, if it has one.
+    fn get_copied<T: Component + Copy>(&self) -> Option<T>;
+
+    /// Returns the entity's dereferenced component, if it has one.
+    fn get_deref<T: Component + Deref>(&self) -> Option<&T::Target>;
+
+    /// Returns a copy of the entity's dereferenced component, or `value` if it doesn't have one.
+    fn get_deref_copied_or<T>(&self, value: T::Target) -> T::Target
+    where
+        T: Component + Deref<Target: Copy>;
+}
+
+macro_rules! impl_entity_values_ext {
+    ($($Entity:ident),*) => {
+        $(
+            impl EntityValuesExt for $Entity<'_> {
+                fn get_copied<T: Component + Copy>(&self) -> Option<T> {
+                    self.get::<T>().copied()
+                }
+
+                fn get_deref<T: Component + Deref>(&self) -> Option<&T::Target> {
+                    self.get::<T>().map(Deref::deref)
+                }
+
+                fn get_deref_copied_or<T>(&self, value: T::Target) -> T::Target
+                where
+                    T: Component + Deref<Target: Copy>,
+                {
+                    self.get::<T>().map_or(value, |t| *t.deref())
+                }
+            }
+        )*
+    };
+}
+
+impl_entity_values_ext!(EntityRef, EntityMut, EntityWorldMut);
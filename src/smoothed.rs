@@ -0,0 +1,224 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::{Add, Deref, Mul, Sub};
+
+use bevy::app::{App, Last, Plugin};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::ecs::system::{Query, Res, Resource};
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+
+/// Types that can be smoothed with an exponential moving average, like `f32` and `Vec3`.
+pub trait Smoothable:
+    Copy + Send + Sync + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+}
+
+impl<T> Smoothable for T where
+    T: Copy + Send + Sync + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T>
+{
+}
+
+/// A plugin that keeps an exponential moving average of the dereferenced value of component `T`,
+/// for use with [`Smoothed`]
+///
+/// Every frame, in [`Last`], the average moves `alpha` of the way toward the current value, so an
+/// `alpha` of `1.0` doesn't smooth at all and smaller values smooth more. The average is stored in
+/// a [`SmoothedValue<T>`] component, which is inserted and removed along with `T` using commands
+/// and starts out at the current value. `alpha` is stored in the [`Smoothing<T>`] resource, so it
+/// can be changed later with [`Smoothing::set_alpha`].
+///
+/// `alpha` is applied once per frame, not per second, so how much the value is smoothed depends
+/// on the frame rate. Run the app at a fixed frame rate, or update [`Smoothing<T>`] from the frame
+/// time, if that matters.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct AudioLevel(f32);
+///
+/// App::new().add_plugins(SmoothedPlugin::<AudioLevel>::new(0.1));
+/// ```
+/// ## Counter Example: Alpha must be between 0 and 1
+/// ```should_panic
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct AudioLevel(f32);
+///
+/// App::new().add_plugins(SmoothedPlugin::<AudioLevel>::new(1.5));
+/// ```
+pub struct SmoothedPlugin<T> {
+    alpha: f32,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> SmoothedPlugin<T> {
+    /// Creates the plugin with the fraction the average moves toward the current value each frame.
+    ///
+    /// Panics if `alpha` is not between `0.0` and `1.0`.
+    pub fn new(alpha: f32) -> Self {
+        assert_alpha(alpha);
+        Self {
+            alpha,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Debug for SmoothedPlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SmoothedPlugin")
+            .field("alpha", &self.alpha)
+            .finish()
+    }
+}
+
+impl<T: Component + Deref<Target: Smoothable>> Plugin for SmoothedPlugin<T> {
+    fn build(&self, app: &mut App) {
+        add_companion::<T, SmoothedValue<T>>(app)
+            .insert_resource(Smoothing::<T>::new(self.alpha))
+            .add_systems(Last, smooth::<T>);
+    }
+}
+
+/// The fraction the average of component `T` moves toward its current value each frame, used by
+/// [`SmoothedPlugin<T>`]
+#[derive(Resource)]
+pub struct Smoothing<T> {
+    alpha: f32,
+    _phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Smoothing<T> {
+    /// Creates the resource with the given `alpha`.
+    ///
+    /// Panics if `alpha` is not between `0.0` and `1.0`.
+    pub fn new(alpha: f32) -> Self {
+        assert_alpha(alpha);
+        Self {
+            alpha,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Returns the fraction per frame, from `0.0` to `1.0`.
+    pub fn alpha(&self) -> f32 {
+        self.alpha
+    }
+
+    /// Sets the fraction per frame.
+    ///
+    /// Panics if `alpha` is not between `0.0` and `1.0`.
+    pub fn set_alpha(&mut self, alpha: f32) {
+        assert_alpha(alpha);
+        self.alpha = alpha;
+    }
+}
+
+fn assert_alpha(alpha: f32) {
+    assert!(
+        (0.0..=1.0).contains(&alpha),
+        "Smoothing alpha must be between 0.0 and 1.0, got {alpha}"
+    );
+}
+
+impl<T> Debug for Smoothing<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Smoothing")
+            .field("alpha", &self.alpha)
+            .finish()
+    }
+}
+
+/// The exponential moving average of the dereferenced value of component `T`, kept by
+/// [`SmoothedPlugin<T>`]
+///
+/// Use [`Smoothed<T>`] to read it in queries.
+#[derive(Component)]
+pub struct SmoothedValue<T: Deref<Target: Smoothable> + Send + Sync + 'static>(T::Target);
+
+impl<T> Debug for SmoothedValue<T>
+where
+    T: Deref<Target: Smoothable + Debug> + Send + Sync + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SmoothedValue").field(&self.0).finish()
+    }
+}
+
+impl<T: Component + Deref<Target: Smoothable>> Companion<T> for SmoothedValue<T> {
+    fn new(value: &T) -> Self {
+        Self(*value.deref())
+    }
+}
+
+fn smooth<T: Component + Deref<Target: Smoothable>>(
+    mut query: Query<(&T, &mut SmoothedValue<T>)>,
+    smoothing: Res<Smoothing<T>>,
+) {
+    for (value, mut smoothed) in query.iter_mut() {
+        smoothed.0 = smoothed.0 + (*value.deref() - smoothed.0) * smoothing.alpha();
+    }
+}
+
+#[derive(Debug)]
+pub struct SmoothedQ<T>(PhantomData<T>);
+
+/// Returns the exponential moving average of the dereferenced value of component `T`
+///
+/// Requires [`SmoothedPlugin<T>`] to be added to the app.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref, DerefMut)]
+/// struct AudioLevel(f32);
+///
+/// fn example(query: Query<Smoothed<AudioLevel>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(SmoothedPlugin::<AudioLevel>::new(0.5));
+/// # let entity = app.world_mut().spawn(AudioLevel(0.0)).id();
+/// # app.update();
+/// # **app.world_mut().get_mut::<AudioLevel>(entity).unwrap() = 1.0;
+/// # let mut query = app.world_mut().query::<Smoothed<AudioLevel>>();
+/// # app.update();
+/// # assert_eq!(query.single(app.world()), 0.5);
+/// # app.update();
+/// # assert_eq!(query.single(app.world()), 0.75);
+/// # app.world_mut().resource_mut::<Smoothing<AudioLevel>>().set_alpha(1.0);
+/// # app.update();
+/// # assert_eq!(query.single(app.world()), 1.0);
+/// ```
+/// ## Counter Example: Dereferenced type must be smoothable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Level(u32);
+///
+/// fn bad_example(query: Query<Smoothed<Level>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type Smoothed<T> = ModQ<SmoothedQ<T>>;
+impl<T: Component + Deref<Target: Smoothable>> ModQuery for SmoothedQ<T> {
+    type FromQuery = &'static SmoothedValue<T>;
+    type ModItem<'a> = T::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.0
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,239 @@
+use bevy::color::Color;
+use bevy::ecs::change_detection::Mut;
+use bevy::ecs::query::WorldQuery;
+use bevy::sprite::Sprite;
+
+use super::base::{ModQ, ModQMut, ModQuery, ModQueryMut};
+
+#[derive(Debug)]
+pub struct SpriteColorOfQ;
+#[derive(Debug)]
+pub struct SpriteFlipXFlagQ;
+#[derive(Debug)]
+pub struct SpriteFlipYFlagQ;
+#[derive(Debug)]
+pub struct TextureAtlasIndexOfQ;
+#[derive(Debug)]
+pub struct TextureAtlasIndexMutQ;
+
+/// Returns the color tint of an entity's [`Sprite`], copied
+///
+/// Requires the `bevy_sprite` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<SpriteColorOf>) {
+///     let _: Color = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Sprite::from_color(Color::WHITE, Vec2::ONE));
+/// # let mut query = world.query::<SpriteColorOf>();
+/// # assert_eq!(query.single(&world), Color::WHITE);
+/// ```
+/// ## Counter Example: The color is a copy, so it can't be used to change the sprite
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(mut query: Query<SpriteColorOf>) {
+///     for color in query.iter_mut() {
+///         *color = Color::BLACK;
+///     }
+/// }
+/// ```
+pub type SpriteColorOf = ModQ<SpriteColorOfQ>;
+impl ModQuery for SpriteColorOfQ {
+    type FromQuery = &'static Sprite;
+    type ModItem<'a> = Color;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.color
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity's [`Sprite`] is flipped horizontally
+///
+/// Useful for facing systems that only need the flip. Requires the `bevy_sprite` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<SpriteFlipXFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Sprite {
+/// #     flip_x: true,
+/// #     ..default()
+/// # });
+/// # let mut query = world.query::<SpriteFlipXFlag>();
+/// # assert!(query.single(&world));
+/// ```
+/// ## Counter Example: SpriteFlipXFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, SpriteFlipXFlag>) {
+///     for _flipped in query.iter() {}
+/// }
+/// ```
+pub type SpriteFlipXFlag = ModQ<SpriteFlipXFlagQ>;
+impl ModQuery for SpriteFlipXFlagQ {
+    type FromQuery = &'static Sprite;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.flip_x
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity's [`Sprite`] is flipped vertically
+///
+/// Requires the `bevy_sprite` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<SpriteFlipYFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Sprite::default());
+/// # let mut query = world.query::<SpriteFlipYFlag>();
+/// # assert!(!query.single(&world));
+/// ```
+/// ## Counter Example: SpriteFlipYFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, SpriteFlipYFlag>) {
+///     for _flipped in query.iter() {}
+/// }
+/// ```
+pub type SpriteFlipYFlag = ModQ<SpriteFlipYFlagQ>;
+impl ModQuery for SpriteFlipYFlagQ {
+    type FromQuery = &'static Sprite;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.flip_y
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the index of the texture atlas section an entity's [`Sprite`] displays, or `None` if
+/// the sprite doesn't use a texture atlas
+///
+/// Requires the `bevy_sprite` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<TextureAtlasIndexOf>) {
+///     let _: Option<usize> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let sheet = world.spawn(Sprite::from_atlas_image(
+/// #     Handle::default(),
+/// #     TextureAtlas { layout: Handle::default(), index: 3 },
+/// # )).id();
+/// # let plain = world.spawn(Sprite::default()).id();
+/// # let mut query = world.query::<TextureAtlasIndexOf>();
+/// # assert_eq!(query.get(&world, sheet).unwrap(), Some(3));
+/// # assert_eq!(query.get(&world, plain).unwrap(), None);
+/// ```
+/// ## Counter Example: The result is optional, since not every sprite uses a texture atlas
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<TextureAtlasIndexOf>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+/// ```
+pub type TextureAtlasIndexOf = ModQ<TextureAtlasIndexOfQ>;
+impl ModQuery for TextureAtlasIndexOfQ {
+    type FromQuery = &'static Sprite;
+    type ModItem<'a> = Option<usize>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.texture_atlas.as_ref().map(|atlas| atlas.index)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the index of the texture atlas section an entity's [`Sprite`] displays as a mutable
+/// reference, or `None` if the sprite doesn't use a texture atlas. The read-only form is
+/// [`TextureAtlasIndexOf`].
+///
+/// Bevy 0.15 stores the texture atlas inside `Sprite`, so this takes mutable access to the whole
+/// `Sprite`. It conflicts with every other system that reads or writes the sprite, such as one
+/// that changes its color, exactly as `&mut Sprite` would. Change detection is only triggered
+/// when the index is written to.
+///
+/// Requires the `bevy_sprite` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(mut query: Query<TextureAtlasIndexMut>) {
+///     for index in query.iter_mut() {
+///         if let Some(mut index) = index {
+///             *index = (*index + 1) % 8;
+///         }
+///     }
+///     let _: Option<usize> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let sheet = world.spawn(Sprite::from_atlas_image(
+/// #     Handle::default(),
+/// #     TextureAtlas { layout: Handle::default(), index: 3 },
+/// # )).id();
+/// # let mut query = world.query::<TextureAtlasIndexMut>();
+/// # *query.single_mut(&mut world).unwrap() = 4;
+/// # assert_eq!(world.get::<Sprite>(sheet).unwrap().texture_atlas.as_ref().unwrap().index, 4);
+/// ```
+/// ## Counter Example: The whole sprite is borrowed, so it can't be read alongside
+/// ```should_panic
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// let mut world = World::new();
+/// let _ = world.query::<(TextureAtlasIndexMut, SpriteColorOf)>();
+/// ```
+pub type TextureAtlasIndexMut = ModQMut<TextureAtlasIndexMutQ>;
+impl ModQueryMut for TextureAtlasIndexMutQ {
+    type FromQuery = &'static mut Sprite;
+    type ModItem<'a> = Option<Mut<'a, usize>>;
+    type ReadOnly = TextureAtlasIndexOf;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.filter_map_unchanged(|sprite| sprite.texture_atlas.as_mut().map(|atlas| &mut atlas.index))
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,126 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+use bevy::app::{App, First, Plugin};
+use bevy::ecs::component::Component;
+use bevy::ecs::query::{Changed, WorldQuery};
+use bevy::ecs::system::Query;
+
+use super::base::{ModQ, ModQuery};
+use super::companion::{add_companion, Companion};
+
+/// A plugin that records the value of component `T` at the start of every frame, for use with
+/// [`StartOfFrame`]
+///
+/// The value is stored in a [`StartOfFrameValue<T>`] component, which is inserted and removed
+/// along with `T` using commands. The value is recorded in [`First`], so systems that change `T`
+/// in `First` may or may not be included.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Position(Vec2);
+///
+/// App::new().add_plugins(StartOfFramePlugin::<Position>::default());
+/// ```
+pub struct StartOfFramePlugin<T>(PhantomData<fn() -> T>);
+
+impl<T> Default for StartOfFramePlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Debug for StartOfFramePlugin<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("StartOfFramePlugin").finish()
+    }
+}
+
+impl<T: Component + Clone> Plugin for StartOfFramePlugin<T> {
+    fn build(&self, app: &mut App) {
+        add_companion::<T, StartOfFrameValue<T>>(app)
+            .add_systems(First, record_start_of_frame::<T>);
+    }
+}
+
+/// The value component `T` had at the start of this frame, recorded by
+/// [`StartOfFramePlugin<T>`]
+///
+/// Use [`StartOfFrame<T>`] to read it in queries.
+#[derive(Component, Debug)]
+pub struct StartOfFrameValue<T: Component + Clone>(T);
+
+impl<T: Component + Clone> Companion<T> for StartOfFrameValue<T> {
+    fn new(value: &T) -> Self {
+        Self(value.clone())
+    }
+}
+
+fn record_start_of_frame<T: Component + Clone>(
+    mut query: Query<(&T, &mut StartOfFrameValue<T>), Changed<T>>,
+) {
+    for (value, mut start_of_frame) in query.iter_mut() {
+        start_of_frame.0.clone_from(value);
+    }
+}
+
+#[derive(Debug)]
+pub struct StartOfFrameQ<T>(PhantomData<T>);
+
+/// Returns the value component `T` had at the start of this frame
+///
+/// This stays the same no matter what systems earlier in the frame wrote to `T`, so every system
+/// reads a consistent snapshot. Requires [`StartOfFramePlugin<T>`] to be added to the app. If `T`
+/// was added this frame, this is its value when it was added, once commands are applied.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Position(Vec2);
+///
+/// fn example(query: Query<(&Position, StartOfFrame<Position>)>) {
+///     for (position, start) in query.iter() {
+///         let _: Vec2 = position.0 - start.0;
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(StartOfFramePlugin::<Position>::default());
+/// # let entity = app.world_mut().spawn(Position(Vec2::ZERO)).id();
+/// # app.update();
+/// # app.world_mut().get_mut::<Position>(entity).unwrap().0 = Vec2::ONE;
+/// # let mut query = app.world_mut().query::<StartOfFrame<Position>>();
+/// # assert_eq!(query.single(app.world()).0, Vec2::ZERO);
+/// # app.update();
+/// # app.world_mut().get_mut::<Position>(entity).unwrap().0 = Vec2::X;
+/// # assert_eq!(query.single(app.world()).0, Vec2::ONE);
+/// ```
+/// ## Counter Example: Component must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Position(Vec2);
+///
+/// fn bad_example(query: Query<StartOfFrame<Position>>) {
+///     let _: &Position = query.get_single().unwrap();
+/// }
+/// ```
+pub type StartOfFrame<T> = ModQ<StartOfFrameQ<T>>;
+impl<T: Component + Clone> ModQuery for StartOfFrameQ<T> {
+    type FromQuery = &'static StartOfFrameValue<T>;
+    type ModItem<'a> = &'a T;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        &t.0
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
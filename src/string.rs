@@ -0,0 +1,504 @@
+use std::borrow::Cow;
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+use super::provider::Provider;
+
+#[derive(Debug)]
+pub struct AsStrQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct OrStrQ<T, P>(PhantomData<(T, P)>);
+#[derive(Debug)]
+pub struct TrimmedStrQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct StrLenQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct StrCharCountQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct CowStrOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ContainsSubstrFlagQ<T, P>(PhantomData<(T, P)>);
+#[derive(Debug)]
+pub struct DisplayStringQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct DebugStringQ<T>(PhantomData<T>);
+
+/// Returns the `&str` of a component that dereferences to a string type such as `String`
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct DisplayName(String);
+///
+/// fn example(query: Query<AsStr<DisplayName>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced type must be a string
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct DisplayName(Vec<char>);
+///
+/// fn bad_example(query: Query<AsStr<DisplayName>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type AsStr<T> = ModQ<AsStrQ<T>>;
+impl<T: Component + Deref> ModQuery for AsStrQ<T>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_ref()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// If the string query exists on the entity it is returned, or else the string provided by `P`.
+///
+/// It's unlikely you'll use this by itself, see [`AsStrOr`] and [`CowStrOr`] for examples of its
+/// use.
+pub type OrStr<T, P> = ModQ<OrStrQ<T, P>>;
+
+/// Returns the `&str` of a component that dereferences to a string type, or the string provided
+/// by `P` if the entity does not have the component (alias of `OrStr<AsStr<T>, P>`)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct DisplayName(String);
+///
+/// struct Unnamed;
+///
+/// impl Provider<&'static str> for Unnamed {
+///     const VALUE: &'static str = "<unnamed>";
+/// }
+///
+/// fn example(query: Query<AsStrOr<DisplayName, Unnamed>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let named = world.spawn(DisplayName("Alice".to_string())).id();
+/// # let unnamed = world.spawn_empty().id();
+/// # let mut query = world.query::<AsStrOr<DisplayName, Unnamed>>();
+/// # assert_eq!(query.get(&world, named).unwrap(), "Alice");
+/// # assert_eq!(query.get(&world, unnamed).unwrap(), "<unnamed>");
+/// ```
+pub type AsStrOr<T, P> = OrStr<AsStr<T>, P>;
+impl<T: Component + Deref, P: Provider<&'static str>> ModQuery for OrStrQ<AsStr<T>, P>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = Option<&'static T>;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(P::VALUE, |t| t.deref().as_ref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the `&str` of a component that dereferences to a string type, with leading and
+/// trailing whitespace removed (a la [`str::trim`])
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct TextInput(String);
+///
+/// fn example(query: Query<TrimmedStr<TextInput>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(TextInput("  hello world\n".to_string()));
+/// # let mut query = world.query::<TrimmedStr<TextInput>>();
+/// # assert_eq!(query.single(&world), "hello world");
+/// ```
+/// ## Counter Example: Dereferenced type must be a string
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct TextInput(Vec<char>);
+///
+/// fn bad_example(query: Query<TrimmedStr<TextInput>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type TrimmedStr<T> = ModQ<TrimmedStrQ<T>>;
+impl<T: Component + Deref> ModQuery for TrimmedStrQ<T>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_ref().trim()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the length in bytes of a component that dereferences to a string type (a la
+/// [`str::len`])
+///
+/// See [`StrCharCount`] for the length in characters.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct ChatMessage(String);
+///
+/// fn example(query: Query<StrLen<ChatMessage>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(ChatMessage("héllo".to_string()));
+/// # let mut query = world.query::<StrLen<ChatMessage>>();
+/// # assert_eq!(query.single(&world), 6);
+/// ```
+/// ## Counter Example: Dereferenced type must be a string
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct ChatMessage(Vec<char>);
+///
+/// fn bad_example(query: Query<StrLen<ChatMessage>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+/// ```
+pub type StrLen<T> = ModQ<StrLenQ<T>>;
+impl<T: Component + Deref> ModQuery for StrLenQ<T>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_ref().len()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the number of characters in a component that dereferences to a string type (a la
+/// `str::chars().count()`)
+///
+/// This has to walk the whole string, so prefer [`StrLen`] if the length in bytes will do.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct ChatMessage(String);
+///
+/// fn example(query: Query<StrCharCount<ChatMessage>>) {
+///     let _: usize = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(ChatMessage("héllo".to_string()));
+/// # let mut query = world.query::<StrCharCount<ChatMessage>>();
+/// # assert_eq!(query.single(&world), 5);
+/// ```
+pub type StrCharCount<T> = ModQ<StrCharCountQ<T>>;
+impl<T: Component + Deref> ModQuery for StrCharCountQ<T>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = usize;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_ref().chars().count()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a `String` of the component formatted with its [`Display`] implementation
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// use std::fmt;
+///
+/// #[derive(Component)]
+/// struct Gold(u32);
+///
+/// impl fmt::Display for Gold {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "{}g", self.0)
+///     }
+/// }
+///
+/// fn example(query: Query<DisplayString<Gold>>) {
+///     let _: String = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Gold(25));
+/// # let mut query = world.query::<DisplayString<Gold>>();
+/// # assert_eq!(query.single(&world), "25g");
+/// ```
+/// ## Counter Example: Component must implement Display
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Gold(u32);
+///
+/// fn bad_example(query: Query<DisplayString<Gold>>) {
+///     let _: String = query.get_single().unwrap();
+/// }
+/// ```
+pub type DisplayString<T> = ModQ<DisplayStringQ<T>>;
+impl<T: Component + Display> ModQuery for DisplayStringQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = String;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.to_string()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a `String` of the component formatted with its [`Debug`] implementation
+///
+/// Useful for dev overlays and logging systems that dump components.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Debug)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// fn example(query: Query<(Entity, DebugString<Velocity>)>) {
+///     for (entity, velocity) in query.iter() {
+///         debug!("{entity}: {velocity}");
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Velocity { x: 1.0, y: 0.5 });
+/// # let mut query = world.query::<DebugString<Velocity>>();
+/// # assert_eq!(query.single(&world), "Velocity { x: 1.0, y: 0.5 }");
+/// ```
+/// ## Counter Example: Component must implement Debug
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Velocity {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// fn bad_example(query: Query<DebugString<Velocity>>) {
+///     let _: String = query.get_single().unwrap();
+/// }
+/// ```
+pub type DebugString<T> = ModQ<DebugStringQ<T>>;
+impl<T: Component + Debug> ModQuery for DebugStringQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = String;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        format!("{t:?}")
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the `&str` of a component that dereferences to a `Cow<'static, str>`, whether it is
+/// borrowed or owned
+///
+/// This is the same as [`AsStr`], but only accepts `Cow` components, documenting that the
+/// component may hold either a static or an owned string.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::borrow::Cow;
+/// #[derive(Component, Deref)]
+/// struct Label(Cow<'static, str>);
+///
+/// fn example(query: Query<CowStrOf<Label>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let borrowed = world.spawn(Label(Cow::Borrowed("static"))).id();
+/// # let owned = world.spawn(Label(Cow::Owned("owned".to_string()))).id();
+/// # let mut query = world.query::<CowStrOf<Label>>();
+/// # assert_eq!(query.get(&world, borrowed).unwrap(), "static");
+/// # assert_eq!(query.get(&world, owned).unwrap(), "owned");
+/// ```
+/// ## Counter Example: Dereferenced type must be a `Cow<'static, str>`
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Label(String);
+///
+/// fn bad_example(query: Query<CowStrOf<Label>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type CowStrOf<T> = ModQ<CowStrOfQ<T>>;
+impl<T: Component + Deref<Target = Cow<'static, str>>> ModQuery for CowStrOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the `&str` of a component that dereferences to a `Cow<'static, str>`, or the string
+/// provided by `P` if the entity does not have the component (alias of `OrStr<CowStrOf<T>, P>`)
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::borrow::Cow;
+/// #[derive(Component, Deref)]
+/// struct Label(Cow<'static, str>);
+///
+/// struct NoLabel;
+///
+/// impl Provider<&'static str> for NoLabel {
+///     const VALUE: &'static str = "";
+/// }
+///
+/// fn example(query: Query<CowStrOr<Label, NoLabel>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type CowStrOr<T, P> = OrStr<CowStrOf<T>, P>;
+impl<T, P> ModQuery for OrStrQ<CowStrOf<T>, P>
+where
+    T: Component + Deref<Target = Cow<'static, str>>,
+    P: Provider<&'static str>,
+{
+    type FromQuery = Option<&'static T>;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.map_or(P::VALUE, |t| t.deref())
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns `true` if a component that dereferences to a string type contains the substring
+/// provided by `P`
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Tags(String);
+///
+/// struct Hostile;
+///
+/// impl Provider<&'static str> for Hostile {
+///     const VALUE: &'static str = "hostile";
+/// }
+///
+/// fn example(query: Query<ContainsSubstrFlag<Tags, Hostile>>) {
+///     let _is_hostile: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let enemy = world.spawn(Tags("flying,hostile".to_string())).id();
+/// # let friend = world.spawn(Tags("flying".to_string())).id();
+/// # let mut query = world.query::<ContainsSubstrFlag<Tags, Hostile>>();
+/// # assert!(query.get(&world, enemy).unwrap());
+/// # assert!(!query.get(&world, friend).unwrap());
+/// ```
+/// ## Counter Example: Substring must be provided
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Tags(String);
+///
+/// struct Hostile;
+///
+/// fn bad_example(query: Query<ContainsSubstrFlag<Tags, Hostile>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type ContainsSubstrFlag<T, P> = ModQ<ContainsSubstrFlagQ<T, P>>;
+impl<T: Component + Deref, P: Provider<&'static str>> ModQuery for ContainsSubstrFlagQ<T, P>
+where
+    <T as Deref>::Target: AsRef<str>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().as_ref().contains(P::VALUE)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
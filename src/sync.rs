@@ -0,0 +1,465 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::sync::atomic::{self, Ordering};
+use std::sync::{Arc, LazyLock, OnceLock, Weak};
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+
+use super::base::{ModQ, ModQuery};
+use super::provider::Provider;
+
+/// Atomic types that can be loaded with a given memory ordering.
+///
+/// Used by [`AtomicLoaded`].
+pub trait AtomicLike {
+    type Value: Copy;
+
+    fn load(&self, ordering: Ordering) -> Self::Value;
+}
+
+macro_rules! impl_atomic_like {
+    ($($width:literal: $Atomic:ty => $value:ty),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicLike for $Atomic {
+                type Value = $value;
+
+                #[inline]
+                fn load(&self, ordering: Ordering) -> $value {
+                    <$Atomic>::load(self, ordering)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_like!(
+    "8": atomic::AtomicBool => bool,
+    "8": atomic::AtomicU8 => u8,
+    "8": atomic::AtomicI8 => i8,
+    "16": atomic::AtomicU16 => u16,
+    "16": atomic::AtomicI16 => i16,
+    "32": atomic::AtomicU32 => u32,
+    "32": atomic::AtomicI32 => i32,
+    "64": atomic::AtomicU64 => u64,
+    "64": atomic::AtomicI64 => i64,
+    "ptr": atomic::AtomicUsize => usize,
+    "ptr": atomic::AtomicIsize => isize,
+);
+
+/// Provides [`Ordering::Relaxed`] to [`AtomicLoaded`].
+#[derive(Debug)]
+pub struct LoadRelaxed;
+/// Provides [`Ordering::Acquire`] to [`AtomicLoaded`]. This is the default ordering.
+#[derive(Debug)]
+pub struct LoadAcquire;
+/// Provides [`Ordering::SeqCst`] to [`AtomicLoaded`].
+#[derive(Debug)]
+pub struct LoadSeqCst;
+
+impl Provider<Ordering> for LoadRelaxed {
+    const VALUE: Ordering = Ordering::Relaxed;
+}
+impl Provider<Ordering> for LoadAcquire {
+    const VALUE: Ordering = Ordering::Acquire;
+}
+impl Provider<Ordering> for LoadSeqCst {
+    const VALUE: Ordering = Ordering::SeqCst;
+}
+
+/// Cells holding a value that is initialized at most once.
+///
+/// Used by [`OnceLockGet`] and [`OnceLockGetOr`].
+pub trait OnceLike {
+    type Value;
+
+    fn get_value(&self) -> Option<&Self::Value>;
+}
+
+impl<U> OnceLike for OnceLock<U> {
+    type Value = U;
+
+    #[inline]
+    fn get_value(&self) -> Option<&U> {
+        self.get()
+    }
+}
+
+/// `LazyLock` is forced when read, so it always has a value.
+impl<U, F: FnOnce() -> U> OnceLike for LazyLock<U, F> {
+    type Value = U;
+
+    #[inline]
+    fn get_value(&self) -> Option<&U> {
+        Some(LazyLock::force(self))
+    }
+}
+
+/// Shared pointer types whose clones only bump a reference count.
+///
+/// Used by [`ArcCloned`] to guarantee that cloning is cheap. This trait is sealed, so it can't be
+/// implemented for types whose clones might not be cheap.
+///
+/// ## Counter Example: Can't be implemented outside this crate
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// #[derive(Clone)]
+/// struct NavMesh(Vec<u32>);
+///
+/// impl ArcLike for NavMesh {}
+/// ```
+pub trait ArcLike: Clone + sealed::Sealed {}
+
+impl<U: ?Sized> ArcLike for Arc<U> {}
+
+mod sealed {
+    use std::sync::Arc;
+
+    pub trait Sealed {}
+
+    impl<U: ?Sized> Sealed for Arc<U> {}
+}
+
+/// Weak pointer types that can be upgraded to a strong pointer if the value is still alive.
+///
+/// Used by [`WeakUpgraded`].
+pub trait WeakLike {
+    type Strong;
+
+    fn upgrade(&self) -> Option<Self::Strong>;
+}
+
+impl<U: ?Sized> WeakLike for Weak<U> {
+    type Strong = Arc<U>;
+
+    #[inline]
+    fn upgrade(&self) -> Option<Arc<U>> {
+        Weak::upgrade(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct AtomicLoadedQ<T, O>(PhantomData<(T, O)>);
+#[derive(Debug)]
+pub struct OnceLockGetQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct OnceLockGetOrQ<T, P>(PhantomData<(T, P)>);
+#[derive(Debug)]
+pub struct ArcClonedQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct WeakUpgradedQ<T>(PhantomData<T>);
+
+/// Returns the value of the atomic a component dereferences to, loaded with the ordering
+/// provided by `O` ([`LoadAcquire`] by default)
+///
+/// Useful for counters and flags shared with worker threads.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// #[derive(Component, Deref)]
+/// struct ChunksLoaded(AtomicU32);
+///
+/// fn example(query: Query<AtomicLoaded<ChunksLoaded>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+///
+/// fn relaxed_example(query: Query<AtomicLoaded<ChunksLoaded, LoadRelaxed>>) {
+///     let _: u32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let id = world.spawn(ChunksLoaded(AtomicU32::new(3))).id();
+/// # world.get::<ChunksLoaded>(id).unwrap().fetch_add(2, Ordering::Release);
+/// # let mut query = world.query::<AtomicLoaded<ChunksLoaded>>();
+/// # assert_eq!(query.single(&world), 5);
+/// ```
+/// ## Counter Example: Loads cannot use release orderings
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::atomic::{AtomicBool, Ordering};
+/// #[derive(Component, Deref)]
+/// struct Ready(AtomicBool);
+///
+/// struct LoadRelease;
+///
+/// impl Provider<Ordering> for LoadRelease {
+///     const VALUE: Ordering = Ordering::Release;
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Ready(AtomicBool::new(true)));
+/// let mut query = world.query::<AtomicLoaded<Ready, LoadRelease>>();
+/// let _: bool = query.single(&world);
+/// ```
+pub type AtomicLoaded<T, O = LoadAcquire> = ModQ<AtomicLoadedQ<T, O>>;
+impl<T: Component + Deref, O: Provider<Ordering>> ModQuery for AtomicLoadedQ<T, O>
+where
+    <T as Deref>::Target: AtomicLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <<T as Deref>::Target as AtomicLike>::Value;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        const {
+            assert!(
+                !matches!(O::VALUE, Ordering::Release | Ordering::AcqRel),
+                "atomic loads cannot use Release or AcqRel ordering"
+            )
+        };
+        t.deref().load(O::VALUE)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the value of the `OnceLock` a component dereferences to, or `None` if
+/// it has not been initialized yet
+///
+/// `LazyLock` components are also supported, and are initialized when read.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::OnceLock;
+/// struct NavMesh;
+///
+/// #[derive(Component, Deref)]
+/// struct DecodedNavMesh(OnceLock<NavMesh>);
+///
+/// fn example(query: Query<OnceLockGet<DecodedNavMesh>>) {
+///     let _: Option<&NavMesh> = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let id = world.spawn(DecodedNavMesh(OnceLock::new())).id();
+/// # let mut query = world.query::<OnceLockGet<DecodedNavMesh>>();
+/// # assert!(query.single(&world).is_none());
+/// # let _ = world.get::<DecodedNavMesh>(id).unwrap().set(NavMesh);
+/// # assert!(query.single(&world).is_some());
+/// ```
+/// ## Counter Example: Dereferenced type must be a once-initialized cell
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// struct NavMesh;
+///
+/// #[derive(Component, Deref)]
+/// struct DecodedNavMesh(Option<NavMesh>);
+///
+/// fn bad_example(query: Query<OnceLockGet<DecodedNavMesh>>) {
+///     let _: Option<&NavMesh> = query.get_single().unwrap();
+/// }
+/// ```
+pub type OnceLockGet<T> = ModQ<OnceLockGetQ<T>>;
+impl<T: Component + Deref> ModQuery for OnceLockGetQ<T>
+where
+    <T as Deref>::Target: OnceLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<&'a <<T as Deref>::Target as OnceLike>::Value>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().get_value()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a reference to the value of the `OnceLock` a component dereferences to, or the value
+/// provided by `P` if it has not been initialized yet
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::{LazyLock, OnceLock};
+/// #[derive(PartialEq, Debug)]
+/// struct Palette([u8; 3]);
+///
+/// #[derive(Component, Deref)]
+/// struct LoadedPalette(OnceLock<Palette>);
+///
+/// #[derive(Component, Deref)]
+/// struct ComputedPalette(LazyLock<Palette>);
+///
+/// struct Grayscale;
+///
+/// impl Provider<&'static Palette> for Grayscale {
+///     const VALUE: &'static Palette = &Palette([128, 128, 128]);
+/// }
+///
+/// fn example(query: Query<OnceLockGetOr<LoadedPalette, Grayscale>>) {
+///     let _: &Palette = query.get_single().unwrap();
+/// }
+///
+/// fn lazy_example(query: Query<OnceLockGetOr<ComputedPalette, Grayscale>>) {
+///     let _: &Palette = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(LoadedPalette(OnceLock::new()));
+/// # world.spawn(ComputedPalette(LazyLock::new(|| Palette([1, 2, 3]))));
+/// # let mut query = world.query::<OnceLockGetOr<LoadedPalette, Grayscale>>();
+/// # assert_eq!(query.single(&world), &Palette([128, 128, 128]));
+/// # let mut query = world.query::<OnceLockGetOr<ComputedPalette, Grayscale>>();
+/// # assert_eq!(query.single(&world), &Palette([1, 2, 3]));
+/// ```
+/// ## Counter Example: Provider must supply a reference to the same type
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::OnceLock;
+/// struct Palette([u8; 3]);
+///
+/// #[derive(Component, Deref)]
+/// struct LoadedPalette(OnceLock<Palette>);
+///
+/// struct Gray;
+///
+/// impl Provider<&'static [u8; 3]> for Gray {
+///     const VALUE: &'static [u8; 3] = &[128, 128, 128];
+/// }
+///
+/// fn bad_example(query: Query<OnceLockGetOr<LoadedPalette, Gray>>) {
+///     let _: &Palette = query.get_single().unwrap();
+/// }
+/// ```
+pub type OnceLockGetOr<T, P> = ModQ<OnceLockGetOrQ<T, P>>;
+impl<T: Component + Deref, P> ModQuery for OnceLockGetOrQ<T, P>
+where
+    <T as Deref>::Target: OnceLike,
+    <<T as Deref>::Target as OnceLike>::Value: 'static,
+    P: Provider<&'static <<T as Deref>::Target as OnceLike>::Value>,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a <<T as Deref>::Target as OnceLike>::Value;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().get_value().unwrap_or(P::VALUE)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns a clone of the `Arc` a component dereferences to
+///
+/// This is like [`AsDerefCloned`](crate::AsDerefCloned), but only accepts `Arc`s, which
+/// guarantees the clone is just a reference count increment. The result can be kept around after
+/// the query borrow ends, such as to hand shared data to another thread.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::Arc;
+/// struct NavMesh {
+///     triangles: Vec<[Vec3; 3]>,
+/// }
+///
+/// #[derive(Component, Deref)]
+/// struct SharedNavMesh(Arc<NavMesh>);
+///
+/// fn example(query: Query<ArcCloned<SharedNavMesh>>) {
+///     let _: Arc<NavMesh> = query.get_single().unwrap();
+/// }
+/// ```
+/// ## Counter Example: Dereferenced type must be an Arc
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Clone)]
+/// struct NavMesh {
+///     triangles: Vec<[Vec3; 3]>,
+/// }
+///
+/// #[derive(Component, Deref)]
+/// struct SharedNavMesh(NavMesh);
+///
+/// fn bad_example(query: Query<ArcCloned<SharedNavMesh>>) {
+///     let _: NavMesh = query.get_single().unwrap();
+/// }
+/// ```
+pub type ArcCloned<T> = ModQ<ArcClonedQ<T>>;
+impl<T: Component + Deref> ModQuery for ArcClonedQ<T>
+where
+    <T as Deref>::Target: ArcLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = <T as Deref>::Target;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().clone()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the `Weak` pointer a component dereferences to upgraded to an `Arc`, or `None` if the
+/// value has already been dropped
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::{Arc, Weak};
+/// struct AudioStream;
+///
+/// #[derive(Component, Deref)]
+/// struct StreamRef(Weak<AudioStream>);
+///
+/// fn example(query: Query<WeakUpgraded<StreamRef>>) {
+///     let _: Option<Arc<AudioStream>> = query.get_single().unwrap();
+/// }
+///
+/// # let stream = Arc::new(AudioStream);
+/// # let mut world = World::new();
+/// # world.spawn(StreamRef(Arc::downgrade(&stream)));
+/// # let mut query = world.query::<WeakUpgraded<StreamRef>>();
+/// # assert!(query.single(&world).is_some());
+/// # drop(stream);
+/// # assert!(query.single(&world).is_none());
+/// ```
+/// ## Counter Example: Dereferenced type must be a Weak pointer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::sync::Arc;
+/// struct AudioStream;
+///
+/// #[derive(Component, Deref)]
+/// struct StreamRef(Arc<AudioStream>);
+///
+/// fn bad_example(query: Query<WeakUpgraded<StreamRef>>) {
+///     let _: Option<Arc<AudioStream>> = query.get_single().unwrap();
+/// }
+/// ```
+pub type WeakUpgraded<T> = ModQ<WeakUpgradedQ<T>>;
+impl<T: Component + Deref> ModQuery for WeakUpgradedQ<T>
+where
+    <T as Deref>::Target: WeakLike,
+{
+    type FromQuery = &'static T;
+    type ModItem<'a> = Option<<<T as Deref>::Target as WeakLike>::Strong>;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.deref().upgrade()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,854 @@
+use std::borrow::Borrow;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+use bevy::ecs::archetype::Archetype;
+use bevy::ecs::change_detection::Mut;
+use bevy::ecs::component::{Component, Tick};
+use bevy::ecs::entity::Entity;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::ecs::query::{Changed, QueryState, With};
+use bevy::ecs::query::{QueryFilter, ReadOnlyQueryData, WorldQuery};
+use bevy::ecs::removal_detection::RemovedComponents;
+use bevy::ecs::system::{
+    Commands, Query, ReadOnlySystemParam, Res, ResMut, Resource, Single, SystemMeta, SystemParam,
+};
+use bevy::ecs::system::{Deferred, SystemBuffer};
+use bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell;
+use bevy::ecs::world::World;
+
+use super::extensions::AsDeref;
+
+/// Implements [`SystemParam`] for a param that wraps an owned value produced from another,
+/// read-only, param.
+macro_rules! impl_owned_param {
+    ($Param:ident<$R:ident: $($bound:path),+>, $From:ty, |$from:ident| $value:expr) => {
+        // SAFETY: All access is delegated to the read-only `$From` param, and the item does not
+        // borrow from the world.
+        unsafe impl<$R: $($bound +)+> SystemParam for $Param<$R> {
+            type State = <$From as SystemParam>::State;
+            type Item<'w, 's> = $Param<$R>;
+
+            fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+                <$From>::init_state(world, system_meta)
+            }
+
+            #[inline]
+            unsafe fn validate_param(
+                state: &Self::State,
+                system_meta: &SystemMeta,
+                world: UnsafeWorldCell,
+            ) -> bool {
+                // SAFETY: Upheld by the caller.
+                unsafe { <$From>::validate_param(state, system_meta, world) }
+            }
+
+            #[inline]
+            unsafe fn get_param<'w, 's>(
+                state: &'s mut Self::State,
+                system_meta: &SystemMeta,
+                world: UnsafeWorldCell<'w>,
+                change_tick: Tick,
+            ) -> Self::Item<'w, 's> {
+                // SAFETY: Upheld by the caller.
+                let $from = unsafe { <$From>::get_param(state, system_meta, world, change_tick) };
+                $Param($value)
+            }
+        }
+
+        // SAFETY: `$From` is read-only.
+        unsafe impl<$R: $($bound +)+> ReadOnlySystemParam for $Param<$R> {}
+
+        impl<$R: $($bound +)+> $Param<$R> {
+            /// Returns the owned value.
+            pub fn into_inner(self) -> $R {
+                self.0
+            }
+        }
+
+        impl<$R: $($bound +)+> Deref for $Param<$R> {
+            type Target = $R;
+
+            fn deref(&self) -> &$R {
+                &self.0
+            }
+        }
+    };
+}
+
+/// A [`SystemParam`] that dereferences a resource, like [`AsDeref`](crate::AsDeref) does for
+/// components
+///
+/// `DerefRes<R>` dereferences to `R::Target`, and [`DerefRes::into_inner`] returns it with the
+/// lifetime of the world borrow. Like [`Res`], the system is skipped if the resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Resource, Deref)]
+/// struct Score(u32);
+///
+/// fn example(score: DerefRes<Score>) {
+///     let _: u32 = *score;
+///     let _: &u32 = score.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.insert_resource(Score(3));
+/// # world.run_system_once(|score: DerefRes<Score>| assert_eq!(*score, 3)).unwrap();
+/// ```
+/// ## Counter Example: Resource must be Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource)]
+/// struct Score(u32);
+///
+/// fn bad_example(score: DerefRes<Score>) {
+///     let _: u32 = *score;
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct DerefRes<'w, R: Resource + Deref> {
+    res: Res<'w, R>,
+}
+
+impl<'w, R: Resource + Deref> DerefRes<'w, R> {
+    /// Returns the dereferenced value of the resource, with the lifetime of the world borrow.
+    pub fn into_inner(self) -> &'w R::Target {
+        self.res.into_inner().deref()
+    }
+}
+
+impl<R: Resource + Deref> Deref for DerefRes<'_, R> {
+    type Target = R::Target;
+
+    fn deref(&self) -> &R::Target {
+        self.res.deref().deref()
+    }
+}
+
+impl<R: Resource + Deref> Debug for DerefRes<'_, R>
+where
+    R::Target: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DerefRes").field(&self.deref()).finish()
+    }
+}
+
+/// A [`SystemParam`] that mutably dereferences a resource, like
+/// [`AsDerefMut`](crate::AsDerefMut) does for components
+///
+/// `DerefResMut<R>` dereferences to `R::Target`, and [`DerefResMut::into_inner`] returns a
+/// [`Mut`] of it, so change detection only triggers when it's written to. Like [`ResMut`], the
+/// system is skipped if the resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Resource, Deref, DerefMut)]
+/// struct Score(u32);
+///
+/// fn example(mut score: DerefResMut<Score>) {
+///     *score += 1;
+///     let _: Mut<u32> = score.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.insert_resource(Score(3));
+/// # world.run_system_once(|mut score: DerefResMut<Score>| *score = 5).unwrap();
+/// # assert_eq!(world.resource::<Score>().0, 5);
+/// ```
+/// ## Counter Example: Resource must be DerefMut
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource, Deref)]
+/// struct Score(u32);
+///
+/// fn bad_example(mut score: DerefResMut<Score>) {
+///     *score += 1;
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct DerefResMut<'w, R: Resource + DerefMut> {
+    res: ResMut<'w, R>,
+}
+
+impl<'w, R: Resource + DerefMut> DerefResMut<'w, R> {
+    /// Returns the dereferenced value of the resource, with the lifetime of the world borrow.
+    pub fn into_inner(self) -> Mut<'w, R::Target> {
+        self.res.map_unchanged(|res| res.deref_mut())
+    }
+}
+
+impl<R: Resource + DerefMut> Deref for DerefResMut<'_, R> {
+    type Target = R::Target;
+
+    fn deref(&self) -> &R::Target {
+        self.res.deref().deref()
+    }
+}
+
+impl<R: Resource + DerefMut> DerefMut for DerefResMut<'_, R> {
+    fn deref_mut(&mut self) -> &mut R::Target {
+        self.res.deref_mut().deref_mut()
+    }
+}
+
+impl<R: Resource + DerefMut> Debug for DerefResMut<'_, R>
+where
+    R::Target: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DerefResMut").field(&self.deref()).finish()
+    }
+}
+
+/// A [`SystemParam`] that copies a resource when the system starts, like
+/// [`Copied`](crate::Copied) does for components
+///
+/// The copy doesn't borrow from the world, so it can be kept around after the system's other
+/// params are used. `CopiedRes<R>` dereferences to `R`. Like [`Res`], the system is skipped if the
+/// resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Resource, Clone, Copy)]
+/// struct Gravity(f32);
+///
+/// fn example(gravity: CopiedRes<Gravity>) {
+///     let _: Gravity = gravity.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.insert_resource(Gravity(9.8));
+/// # world.run_system_once(|gravity: CopiedRes<Gravity>| assert_eq!(gravity.0, 9.8)).unwrap();
+/// ```
+/// ## Counter Example: Resource must be Copy
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource, Clone)]
+/// struct Gravity(f32);
+///
+/// fn bad_example(gravity: CopiedRes<Gravity>) {
+///     let _: Gravity = gravity.into_inner();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CopiedRes<R: Resource + Copy>(R);
+impl_owned_param!(CopiedRes<R: Resource, Copy>, Res<'static, R>, |res| *res);
+
+/// A [`SystemParam`] that clones a resource when the system starts, like
+/// [`Cloned`](crate::Cloned) does for components
+///
+/// The clone doesn't borrow from the world, so it can be kept around after the system's other
+/// params are used. `ClonedRes<R>` dereferences to `R`. Like [`Res`], the system is skipped if the
+/// resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Resource, Clone)]
+/// struct ServerAddress(String);
+///
+/// fn example(address: ClonedRes<ServerAddress>) {
+///     let _: ServerAddress = address.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.insert_resource(ServerAddress("localhost".to_string()));
+/// # world.run_system_once(|address: ClonedRes<ServerAddress>| assert_eq!(address.0, "localhost"))
+/// #     .unwrap();
+/// ```
+/// ## Counter Example: Resource must be Clone
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource)]
+/// struct ServerAddress(String);
+///
+/// fn bad_example(address: ClonedRes<ServerAddress>) {
+///     let _: ServerAddress = address.into_inner();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ClonedRes<R: Resource + Clone>(R);
+impl_owned_param!(ClonedRes<R: Resource, Clone>, Res<'static, R>, |res| res.clone());
+
+/// A [`SystemParam`] that clones a resource when the system starts, or uses its default if the
+/// resource doesn't exist, like [`ClonedOrDefault`](crate::ClonedOrDefault) does for components
+///
+/// `ResOrDefault<R>` dereferences to `R`. Unlike [`ClonedRes`], the system still runs when the
+/// resource doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Resource, Clone, Default)]
+/// struct Difficulty(u8);
+///
+/// fn example(difficulty: ResOrDefault<Difficulty>) {
+///     let _: Difficulty = difficulty.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.run_system_once(|difficulty: ResOrDefault<Difficulty>| assert_eq!(difficulty.0, 0))
+/// #     .unwrap();
+/// # world.insert_resource(Difficulty(2));
+/// # world.run_system_once(|difficulty: ResOrDefault<Difficulty>| assert_eq!(difficulty.0, 2))
+/// #     .unwrap();
+/// ```
+/// ## Counter Example: Resource must be Default
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Resource, Clone)]
+/// struct Difficulty(u8);
+///
+/// fn bad_example(difficulty: ResOrDefault<Difficulty>) {
+///     let _: Difficulty = difficulty.into_inner();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ResOrDefault<R: Resource + Clone + Default>(R);
+impl_owned_param!(
+    ResOrDefault<R: Resource, Clone, Default>,
+    Option<Res<'static, R>>,
+    |res| res.map(|res| res.clone()).unwrap_or_default()
+);
+
+/// A [`SystemParam`] for the query data of the only entity matching a query, like [`Single`],
+/// for query data that yields owned values
+///
+/// [`SingleCopied::get`] copies the value out, so
+/// `SingleCopied<AsDerefCopied<PlayerHealth>>` gives the player's health directly. Like `Single`,
+/// the system is skipped unless exactly one entity matches.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Deref)]
+/// struct PlayerHealth(f32);
+///
+/// fn example(health: SingleCopied<AsDerefCopied<PlayerHealth>>) {
+///     let _: f32 = health.get();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(PlayerHealth(10.0));
+/// # world.run_system_once(|health: SingleCopied<AsDerefCopied<PlayerHealth>>| {
+/// #     assert_eq!(health.get(), 10.0)
+/// # }).unwrap();
+/// ```
+/// ## Counter Example: Query data must be read-only
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref, DerefMut)]
+/// struct PlayerHealth(f32);
+///
+/// fn bad_example(health: SingleCopied<AsDerefMut<PlayerHealth>>) {
+///     let _: f32 = *health.into_inner();
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct SingleCopied<'w, D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static = ()> {
+    single: Single<'w, D, F>,
+}
+
+impl<'w, D: ReadOnlyQueryData, F: QueryFilter> SingleCopied<'w, D, F> {
+    /// Returns a copy of the query data.
+    pub fn get(&self) -> <D as WorldQuery>::Item<'w>
+    where
+        <D as WorldQuery>::Item<'w>: Copy,
+    {
+        *self.single
+    }
+
+    /// Returns the query data with ownership.
+    pub fn into_inner(self) -> <D as WorldQuery>::Item<'w> {
+        self.single.into_inner()
+    }
+}
+
+impl<'w, D: ReadOnlyQueryData, F: QueryFilter> Deref for SingleCopied<'w, D, F> {
+    type Target = <D as WorldQuery>::Item<'w>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.single
+    }
+}
+
+impl<'w, D: ReadOnlyQueryData, F: QueryFilter> Debug for SingleCopied<'w, D, F>
+where
+    <D as WorldQuery>::Item<'w>: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SingleCopied").field(self.deref()).finish()
+    }
+}
+
+/// A [`SystemParam`] that dereferences the component of the only entity matching a query, like
+/// [`Single`] with [`AsDeref`]
+///
+/// `SingleDeref<T>` dereferences to `T::Target`, and [`SingleDeref::into_inner`] returns it with
+/// the lifetime of the world borrow. Like `Single`, the system is skipped unless exactly one
+/// entity matches.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Deref)]
+/// struct PlayerName(String);
+///
+/// fn example(name: SingleDeref<PlayerName>) {
+///     let _: &str = name.as_str();
+///     let _: &String = name.into_inner();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(PlayerName("Ferris".to_string()));
+/// # world.run_system_once(|name: SingleDeref<PlayerName>| assert_eq!(*name, "Ferris")).unwrap();
+/// ```
+/// ## Counter Example: Component must be Deref
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct PlayerName(String);
+///
+/// fn bad_example(name: SingleDeref<PlayerName>) {
+///     let _: &String = name.into_inner();
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct SingleDeref<'w, T: Component + Deref, F: QueryFilter + 'static = ()> {
+    single: Single<'w, AsDeref<T>, F>,
+}
+
+impl<'w, T: Component + Deref, F: QueryFilter> SingleDeref<'w, T, F> {
+    /// Returns the dereferenced component, with the lifetime of the world borrow.
+    pub fn into_inner(self) -> &'w T::Target {
+        self.single.into_inner()
+    }
+}
+
+impl<T: Component + Deref, F: QueryFilter> Deref for SingleDeref<'_, T, F> {
+    type Target = T::Target;
+
+    fn deref(&self) -> &T::Target {
+        &self.single
+    }
+}
+
+impl<T: Component + Deref, F: QueryFilter> Debug for SingleDeref<'_, T, F>
+where
+    T::Target: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SingleDeref").field(&self.deref()).finish()
+    }
+}
+
+/// A [`SystemParam`] that gets a component of an entity, inserting its default if the entity
+/// doesn't have it
+///
+/// [`EnsureDefault::get`] returns the component if it's present. Otherwise it queues the
+/// insertion of `T::default()` with [`Commands`] and returns the default right away, so the
+/// system doesn't have to wait for the insertion to be applied. It only returns `None` if the
+/// entity doesn't exist.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component, Clone, Default)]
+/// struct Score(u32);
+///
+/// fn example(mut scores: EnsureDefault<Score>, players: Query<Entity, With<Name>>) {
+///     for player in players.iter() {
+///         let _: u32 = scores.get(player).unwrap().0;
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # let with = world.spawn((Name::new("A"), Score(3))).id();
+/// # let without = world.spawn(Name::new("B")).id();
+/// # world.run_system_once(move |mut scores: EnsureDefault<Score>| {
+/// #     assert_eq!(scores.get(with).unwrap().0, 3);
+/// #     assert_eq!(scores.get(without).unwrap().0, 0);
+/// # }).unwrap();
+/// # assert_eq!(world.get::<Score>(with).unwrap().0, 3);
+/// # assert_eq!(world.get::<Score>(without).unwrap().0, 0);
+/// ```
+/// ## Counter Example: Component must be Default
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Clone)]
+/// struct Score(u32);
+///
+/// fn bad_example(mut scores: EnsureDefault<Score>, players: Query<Entity, With<Name>>) {
+///     for player in players.iter() {
+///         let _: u32 = scores.get(player).unwrap().0;
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct EnsureDefault<'w, 's, T: Component + Clone + Default> {
+    query: Query<'w, 's, Option<&'static T>>,
+    commands: Commands<'w, 's>,
+}
+
+impl<T: Component + Clone + Default> EnsureDefault<'_, '_, T> {
+    /// Returns the entity's component, or its default after queueing its insertion.
+    ///
+    /// Returns `None` if the entity doesn't exist.
+    pub fn get(&mut self, entity: Entity) -> Option<Cow<'_, T>> {
+        match self.query.get(entity).ok()? {
+            Some(component) => Some(Cow::Borrowed(component)),
+            None => {
+                self.commands.entity(entity).insert_if_new(T::default());
+                Some(Cow::Owned(T::default()))
+            }
+        }
+    }
+}
+
+impl<T: Component + Clone + Default> Debug for EnsureDefault<'_, '_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnsureDefault").finish_non_exhaustive()
+    }
+}
+
+/// A [`SystemParam`] that looks up entities by the dereferenced value of component `T`, like a
+/// reverse [`Query::get`]
+///
+/// `IndexedQuery<T>` keeps a map from each entity's dereferenced `T` value, as an owned key, to
+/// the entity, and [`IndexedQuery::get`] looks a key up in it. The map is updated incrementally
+/// when the system runs: only entities whose `T` changed are re-indexed, and entities that lost
+/// `T` are found with [`RemovedComponents`]. Only entities matching the filter `F` are returned.
+/// If several matching entities have the same key, the one indexed most recently wins.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct NetId(u64);
+///
+/// #[derive(Component)]
+/// struct Disconnected;
+///
+/// fn example(index: IndexedQuery<NetId, Without<Disconnected>>) {
+///     let _: Option<Entity> = index.get(&7);
+/// }
+///
+/// # let mut world = World::new();
+/// # let a = world.spawn(NetId(7)).id();
+/// # let b = world.spawn(NetId(8)).id();
+/// # let mut system = IntoSystem::into_system(
+/// #     |index: IndexedQuery<NetId, Without<Disconnected>>| {
+/// #         (index.get(&7), index.get(&8), index.get(&9))
+/// #     },
+/// # );
+/// # system.initialize(&mut world);
+/// # assert_eq!(system.run((), &mut world), (Some(a), Some(b), None));
+/// # let c = world.spawn(NetId(7)).id();
+/// # assert_eq!(system.run((), &mut world), (Some(c), Some(b), None));
+/// # world.despawn(c);
+/// # assert_eq!(system.run((), &mut world), (Some(a), Some(b), None));
+/// # world.entity_mut(a).insert(NetId(9));
+/// # world.entity_mut(b).remove::<NetId>();
+/// # assert_eq!(system.run((), &mut world), (None, None, Some(a)));
+/// # world.entity_mut(a).insert(Disconnected);
+/// # assert_eq!(system.run((), &mut world), (None, None, None));
+/// # world.entity_mut(a).remove::<Disconnected>();
+/// # assert_eq!(system.run((), &mut world), (None, None, Some(a)));
+/// ```
+/// ## Counter Example: Dereferenced type must be hashable
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Elevation(f32);
+///
+/// fn bad_example(index: IndexedQuery<Elevation>) {
+///     let _: Option<Entity> = index.get(&7.0);
+/// }
+/// # let _ = IntoSystem::into_system(bad_example);
+/// ```
+pub struct IndexedQuery<'w, 's, T, F = ()>
+where
+    T: Component + Deref<Target: ToOwned>,
+    F: QueryFilter + 'static,
+{
+    entities: &'s HashMap<IndexKey<T>, Vec<Entity>>,
+    matching: Query<'w, 's, (), (With<T>, F)>,
+}
+
+/// The key [`IndexedQuery<T>`] indexes entities by, the owned form of `T`'s dereferenced value
+type IndexKey<T> = <<T as Deref>::Target as ToOwned>::Owned;
+
+impl<T, F> IndexedQuery<'_, '_, T, F>
+where
+    T: Component + Deref<Target: ToOwned<Owned: Eq + Hash>>,
+    F: QueryFilter + 'static,
+{
+    /// Returns the entity whose dereferenced `T` value is `key`, if any.
+    pub fn get<Q>(&self, key: &Q) -> Option<Entity>
+    where
+        IndexKey<T>: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.entities
+            .get(key)?
+            .iter()
+            .rev()
+            .copied()
+            .find(|&entity| self.matching.contains(entity))
+    }
+}
+
+impl<T, F> Debug for IndexedQuery<'_, '_, T, F>
+where
+    T: Component + Deref<Target: ToOwned<Owned: Debug>>,
+    F: QueryFilter + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IndexedQuery").field(self.entities).finish()
+    }
+}
+
+fn unindex<K: Eq + Hash>(by_key: &mut HashMap<K, Vec<Entity>>, key: &K, entity: Entity) {
+    if let Some(entities) = by_key.get_mut(key) {
+        entities.retain(|&indexed| indexed != entity);
+        if entities.is_empty() {
+            by_key.remove(key);
+        }
+    }
+}
+
+// SAFETY: All access is delegated to the read-only queries and `RemovedComponents`, and the index
+// is only stored in the param's state.
+unsafe impl<T, F> SystemParam for IndexedQuery<'_, '_, T, F>
+where
+    T: Component + Deref<Target: ToOwned<Owned: Eq + Hash + Clone + Send + Sync>>,
+    F: QueryFilter + 'static,
+{
+    type State = (
+        QueryState<(Entity, &'static T), Changed<T>>,
+        QueryState<(), (With<T>, F)>,
+        <RemovedComponents<'static, 'static, T> as SystemParam>::State,
+        HashMap<IndexKey<T>, Vec<Entity>>,
+        EntityHashMap<IndexKey<T>>,
+    );
+    type Item<'w, 's> = IndexedQuery<'w, 's, T, F>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        (
+            Query::<(Entity, &T), Changed<T>>::init_state(world, system_meta),
+            Query::<(), (With<T>, F)>::init_state(world, system_meta),
+            RemovedComponents::<T>::init_state(world, system_meta),
+            HashMap::new(),
+            EntityHashMap::default(),
+        )
+    }
+
+    unsafe fn new_archetype(
+        (changed, matching, ..): &mut Self::State,
+        archetype: &Archetype,
+        system_meta: &mut SystemMeta,
+    ) {
+        // SAFETY: Upheld by the caller.
+        unsafe {
+            Query::<(Entity, &T), Changed<T>>::new_archetype(changed, archetype, system_meta);
+            Query::<(), (With<T>, F)>::new_archetype(matching, archetype, system_meta);
+        }
+    }
+
+    unsafe fn get_param<'w, 's>(
+        (changed, matching, removed, by_key, by_entity): &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: Upheld by the caller.
+        let (changed, matching, mut removed) = unsafe {
+            (
+                Query::<(Entity, &T), Changed<T>>::get_param(
+                    changed,
+                    system_meta,
+                    world,
+                    change_tick,
+                ),
+                Query::<(), (With<T>, F)>::get_param(matching, system_meta, world, change_tick),
+                RemovedComponents::<T>::get_param(removed, system_meta, world, change_tick),
+            )
+        };
+        // Removals first, so a component removed and added again is re-indexed below
+        for entity in removed.read() {
+            if let Some(key) = by_entity.remove(&entity) {
+                unindex(by_key, &key, entity);
+            }
+        }
+        for (entity, value) in changed.iter() {
+            let key = value.deref().to_owned();
+            if let Some(old_key) = by_entity.insert(entity, key.clone()) {
+                if old_key == key {
+                    continue;
+                }
+                unindex(by_key, &old_key, entity);
+            }
+            by_key.entry(key).or_default().push(entity);
+        }
+        IndexedQuery {
+            entities: by_key,
+            matching,
+        }
+    }
+}
+
+// SAFETY: The queries and `RemovedComponents` are all read-only.
+unsafe impl<T, F> ReadOnlySystemParam for IndexedQuery<'_, '_, T, F>
+where
+    T: Component + Deref<Target: ToOwned<Owned: Eq + Hash + Clone + Send + Sync>>,
+    F: QueryFilter + 'static,
+{
+}
+
+/// A [`SystemParam`] that reads component `T` like a [`Query`], and queues writes to it that are
+/// applied at the next sync point, like [`Commands`]
+///
+/// Since it only reads `T`, systems that use it can run in parallel with each other and with
+/// systems that read `T`, which is useful for systems that read broadly but write rarely. Queued
+/// writes to entities that no longer have `T` are ignored. Only entities matching the filter `F`
+/// are read.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::system::RunSystemOnce;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn example(mut health: QueuedMut<Health>) {
+///     let hurt: Vec<Entity> = health
+///         .iter()
+///         .filter(|(_, health)| health.0 < 10.0)
+///         .map(|(entity, _)| entity)
+///         .collect();
+///     for entity in hurt {
+///         health.modify(entity, |health| health.0 += 1.0);
+///     }
+/// }
+///
+/// # let mut world = World::new();
+/// # let hurt = world.spawn(Health(5.0)).id();
+/// # let healthy = world.spawn(Health(20.0)).id();
+/// # world.run_system_once(example).unwrap();
+/// # assert_eq!(world.get::<Health>(hurt).unwrap().0, 6.0);
+/// # world.run_system_once(move |mut health: QueuedMut<Health>| {
+/// #     health.set(healthy, Health(30.0));
+/// #     assert_eq!(health.get(healthy).unwrap().0, 20.0);
+/// # }).unwrap();
+/// # assert_eq!(world.get::<Health>(healthy).unwrap().0, 30.0);
+/// ```
+/// ## Counter Example: Components can't be borrowed while queueing writes
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Health(f32);
+///
+/// fn bad_example(mut health: QueuedMut<Health>, entity: Entity) {
+///     let current = health.get(entity).unwrap();
+///     health.set(entity, Health(current.0 + 1.0));
+///     let _: &Health = current;
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct QueuedMut<'w, 's, T: Component, F: QueryFilter + 'static = ()> {
+    query: Query<'w, 's, (Entity, &'static T), F>,
+    queue: Deferred<'s, WriteQueue<T>>,
+}
+
+impl<T: Component, F: QueryFilter> QueuedMut<'_, '_, T, F> {
+    /// Returns the entity's component, without any queued writes applied.
+    pub fn get(&self, entity: Entity) -> Option<&T> {
+        self.query.get(entity).ok().map(|(_, component)| component)
+    }
+
+    /// Iterates over the entities and their components, without any queued writes applied.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &T)> + '_ {
+        self.query.iter()
+    }
+
+    /// Queues replacing the entity's component with `value`.
+    pub fn set(&mut self, entity: Entity, value: T) {
+        self.modify(entity, move |component| *component = value);
+    }
+
+    /// Queues modifying the entity's component with `f`.
+    pub fn modify(&mut self, entity: Entity, f: impl FnOnce(&mut T) + Send + 'static) {
+        self.queue.0.push((entity, Box::new(f)));
+    }
+}
+
+impl<T: Component, F: QueryFilter> Debug for QueuedMut<'_, '_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueuedMut")
+            .field("queued", &self.queue.0.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The writes queued by a [`QueuedMut<T>`], applied at the next sync point
+pub struct WriteQueue<T>(Vec<(Entity, QueuedWrite<T>)>);
+
+type QueuedWrite<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+impl<T> Default for WriteQueue<T> {
+    fn default() -> Self {
+        Self(Vec::new())
+    }
+}
+
+impl<T> Debug for WriteQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WriteQueue").field(&self.0.len()).finish()
+    }
+}
+
+impl<T: Component> SystemBuffer for WriteQueue<T> {
+    fn apply(&mut self, _: &SystemMeta, world: &mut World) {
+        for (entity, write) in self.0.drain(..) {
+            if let Some(mut component) = world.get_mut::<T>(entity) {
+                write(&mut component);
+            }
+        }
+    }
+}
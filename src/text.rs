@@ -0,0 +1,53 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::query::WorldQuery;
+use bevy::text::TextSpanAccess;
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct TextStrOfQ<T>(PhantomData<T>);
+
+/// Returns the `&str` of a text component, such as `Text`, `Text2d` or `TextSpan`
+///
+/// Only the text of the component itself is returned. Spans of a text block are separate child
+/// entities, and aren't included. Requires the `bevy_text` feature, and `Text` also requires the
+/// `bevy_ui` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<TextStrOf<Text2d>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(Text2d::new("Score: 10"));
+/// # let mut query = world.query::<TextStrOf<Text2d>>();
+/// # assert_eq!(query.single(&world), "Score: 10");
+/// ```
+/// ## Counter Example: Component must be a text component
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Label(String);
+///
+/// fn bad_example(query: Query<TextStrOf<Label>>) {
+///     let _: &str = query.get_single().unwrap();
+/// }
+/// ```
+pub type TextStrOf<T> = ModQ<TextStrOfQ<T>>;
+impl<T: TextSpanAccess> ModQuery for TextStrOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = &'a str;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.read_span()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,265 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::time::{Stopwatch, Timer};
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct TimerFinishedFlagQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct TimerJustFinishedFlagQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct TimerFractionOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct TimerRemainingSecsQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct StopwatchElapsedSecsQ<T>(PhantomData<T>);
+
+/// Returns whether the [`Timer`] a component dereferences to has finished
+///
+/// Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(Component, Deref)]
+/// struct Cooldown(Timer);
+///
+/// fn example(query: Query<TimerFinishedFlag<Cooldown>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
+/// # timer.tick(Duration::from_secs(2));
+/// # world.spawn(Cooldown(timer));
+/// # let mut query = world.query::<TimerFinishedFlag<Cooldown>>();
+/// # assert!(query.single(&world));
+/// ```
+/// ## Counter Example: Dereferenced type must be a Timer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::time::Stopwatch;
+/// #[derive(Component, Deref)]
+/// struct Cooldown(Stopwatch);
+///
+/// fn bad_example(query: Query<TimerFinishedFlag<Cooldown>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type TimerFinishedFlag<T> = ModQ<TimerFinishedFlagQ<T>>;
+impl<T: Component + Deref<Target = Timer>> ModQuery for TimerFinishedFlagQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.finished()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether the [`Timer`] a component dereferences to finished during its last tick
+///
+/// Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(Component, Deref)]
+/// struct Cooldown(Timer);
+///
+/// fn example(query: Query<TimerJustFinishedFlag<Cooldown>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let mut timer = Timer::from_seconds(1.0, TimerMode::Once);
+/// # timer.tick(Duration::from_secs(2));
+/// # let id = world.spawn(Cooldown(timer.clone())).id();
+/// # let mut query = world.query::<TimerJustFinishedFlag<Cooldown>>();
+/// # assert!(query.single(&world));
+/// # timer.tick(Duration::from_secs(1));
+/// # world.entity_mut(id).insert(Cooldown(timer));
+/// # assert!(!query.single(&world));
+/// ```
+/// ## Counter Example: Dereferenced type must be a Timer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Cooldown(f32);
+///
+/// fn bad_example(query: Query<TimerJustFinishedFlag<Cooldown>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type TimerJustFinishedFlag<T> = ModQ<TimerJustFinishedFlagQ<T>>;
+impl<T: Component + Deref<Target = Timer>> ModQuery for TimerJustFinishedFlagQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.just_finished()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the fraction of the [`Timer`] a component dereferences to that has elapsed, from 0.0
+/// to 1.0
+///
+/// Useful for feeding progress bars and animation blends. Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(Component, Deref)]
+/// struct Cast(Timer);
+///
+/// fn example(query: Query<TimerFractionOf<Cast>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let mut timer = Timer::from_seconds(2.0, TimerMode::Once);
+/// # timer.tick(Duration::from_millis(500));
+/// # world.spawn(Cast(timer));
+/// # let mut query = world.query::<TimerFractionOf<Cast>>();
+/// # assert_eq!(query.single(&world), 0.25);
+/// ```
+/// ## Counter Example: Dereferenced type must be a Timer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Cast(f32);
+///
+/// fn bad_example(query: Query<TimerFractionOf<Cast>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type TimerFractionOf<T> = ModQ<TimerFractionOfQ<T>>;
+impl<T: Component + Deref<Target = Timer>> ModQuery for TimerFractionOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.fraction()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the seconds remaining on the [`Timer`] a component dereferences to
+///
+/// Useful for HUD countdowns and AI decisions. Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(Component, Deref)]
+/// struct Fuse(Timer);
+///
+/// fn example(query: Query<TimerRemainingSecs<Fuse>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let mut timer = Timer::from_seconds(2.0, TimerMode::Once);
+/// # timer.tick(Duration::from_millis(500));
+/// # world.spawn(Fuse(timer));
+/// # let mut query = world.query::<TimerRemainingSecs<Fuse>>();
+/// # assert_eq!(query.single(&world), 1.5);
+/// ```
+/// ## Counter Example: Dereferenced type must be a Timer
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Fuse(f32);
+///
+/// fn bad_example(query: Query<TimerRemainingSecs<Fuse>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type TimerRemainingSecs<T> = ModQ<TimerRemainingSecsQ<T>>;
+impl<T: Component + Deref<Target = Timer>> ModQuery for TimerRemainingSecsQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.remaining_secs()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the seconds elapsed on the [`Stopwatch`] a component dereferences to
+///
+/// Useful for session, age and uptime components. Requires the `bevy_time` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use std::time::Duration;
+/// # use bevy::time::Stopwatch;
+/// #[derive(Component, Deref)]
+/// struct Age(Stopwatch);
+///
+/// fn example(query: Query<StopwatchElapsedSecs<Age>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let mut stopwatch = Stopwatch::new();
+/// # stopwatch.tick(Duration::from_millis(1500));
+/// # world.spawn(Age(stopwatch));
+/// # let mut query = world.query::<StopwatchElapsedSecs<Age>>();
+/// # assert_eq!(query.single(&world), 1.5);
+/// ```
+/// ## Counter Example: Dereferenced type must be a Stopwatch
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component, Deref)]
+/// struct Age(Timer);
+///
+/// fn bad_example(query: Query<StopwatchElapsedSecs<Age>>) {
+///     let _: f32 = query.get_single().unwrap();
+/// }
+/// ```
+pub type StopwatchElapsedSecs<T> = ModQ<StopwatchElapsedSecsQ<T>>;
+impl<T: Component + Deref<Target = Stopwatch>> ModQuery for StopwatchElapsedSecsQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = f32;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.elapsed_secs()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
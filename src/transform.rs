@@ -0,0 +1,513 @@
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
+
+use bevy::ecs::change_detection::Mut;
+use bevy::ecs::component::Component;
+use bevy::ecs::query::WorldQuery;
+use bevy::math::{Dir3, Quat, Vec2, Vec3};
+use bevy::transform::components::{GlobalTransform, Transform};
+
+use super::base::{ModQ, ModQMut, ModQuery, ModQueryMut};
+
+/// Components that describe a translation, rotation and scale.
+///
+/// Used by adapters such as [`RotationOf`] so they work the same on [`Transform`] and
+/// [`GlobalTransform`]. For `GlobalTransform`, rotation and scale are decomposed from the affine
+/// matrix.
+pub trait TransformLike {
+    fn translation(&self) -> Vec3;
+    fn rotation(&self) -> Quat;
+    fn scale(&self) -> Vec3;
+    fn forward(&self) -> Dir3;
+    fn right(&self) -> Dir3;
+}
+
+impl TransformLike for Transform {
+    #[inline]
+    fn translation(&self) -> Vec3 {
+        self.translation
+    }
+
+    #[inline]
+    fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    #[inline]
+    fn scale(&self) -> Vec3 {
+        self.scale
+    }
+
+    #[inline]
+    fn forward(&self) -> Dir3 {
+        Transform::forward(self)
+    }
+
+    #[inline]
+    fn right(&self) -> Dir3 {
+        Transform::right(self)
+    }
+}
+
+impl TransformLike for GlobalTransform {
+    #[inline]
+    fn translation(&self) -> Vec3 {
+        GlobalTransform::translation(self)
+    }
+
+    #[inline]
+    fn rotation(&self) -> Quat {
+        GlobalTransform::rotation(self)
+    }
+
+    #[inline]
+    fn scale(&self) -> Vec3 {
+        GlobalTransform::scale(self)
+    }
+
+    #[inline]
+    fn forward(&self) -> Dir3 {
+        GlobalTransform::forward(self)
+    }
+
+    #[inline]
+    fn right(&self) -> Dir3 {
+        GlobalTransform::right(self)
+    }
+}
+
+#[derive(Debug)]
+pub struct TranslationCopiedQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct Translation2dQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct Translation2dMutQ;
+#[derive(Debug)]
+pub struct RotationOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ScaleOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct ForwardOfQ<T>(PhantomData<T>);
+#[derive(Debug)]
+pub struct RightOfQ<T>(PhantomData<T>);
+
+/// Returns the translation of an entity's [`Transform`], or of another [`TransformLike`]
+/// component such as [`GlobalTransform`], copied
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<TranslationCopied>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<TranslationCopied<GlobalTransform>>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(1.0, 2.0, 3.0), GlobalTransform::from_xyz(4.0, 5.0, 6.0)));
+/// # let mut query = world.query::<TranslationCopied>();
+/// # assert_eq!(query.single(&world), Vec3::new(1.0, 2.0, 3.0));
+/// # let mut query = world.query::<TranslationCopied<GlobalTransform>>();
+/// # assert_eq!(query.single(&world), Vec3::new(4.0, 5.0, 6.0));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Position(Vec3);
+///
+/// fn bad_example(query: Query<TranslationCopied<Position>>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+/// ```
+pub type TranslationCopied<T = Transform> = ModQ<TranslationCopiedQ<T>>;
+impl<T: Component + TransformLike> ModQuery for TranslationCopiedQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Vec3;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::translation(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the translation of an entity's [`GlobalTransform`]
+///
+/// Shorthand for [`TranslationCopied<GlobalTransform>`](TranslationCopied).
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<GlobalTranslationCopied>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(GlobalTransform::from_xyz(1.0, 2.0, 3.0));
+/// # let mut query = world.query::<GlobalTranslationCopied>();
+/// # assert_eq!(query.single(&world), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+/// ## Counter Example: The global translation is stale until transforms are propagated
+/// ```should_panic
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// let mut world = World::new();
+/// world.spawn(Transform::from_xyz(1.0, 2.0, 3.0));
+/// let mut query = world.query::<GlobalTranslationCopied>();
+/// assert_eq!(query.single(&world), Vec3::new(1.0, 2.0, 3.0));
+/// ```
+pub type GlobalTranslationCopied = TranslationCopied<GlobalTransform>;
+
+/// Returns the x and y of the translation of an entity's [`Transform`], or of another
+/// [`TransformLike`] component such as [`GlobalTransform`], for 2D games
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<Translation2d>) {
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<Translation2d<GlobalTransform>>) {
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn((Transform::from_xyz(1.0, 2.0, 3.0), GlobalTransform::from_xyz(4.0, 5.0, 6.0)));
+/// # let mut query = world.query::<Translation2d>();
+/// # assert_eq!(query.single(&world), Vec2::new(1.0, 2.0));
+/// # let mut query = world.query::<Translation2d<GlobalTransform>>();
+/// # assert_eq!(query.single(&world), Vec2::new(4.0, 5.0));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Position(Vec2);
+///
+/// fn bad_example(query: Query<Translation2d<Position>>) {
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+/// ```
+pub type Translation2d<T = Transform> = ModQ<Translation2dQ<T>>;
+impl<T: Component + TransformLike> ModQuery for Translation2dQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Vec2;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::translation(t).truncate()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the x and y of the translation of an entity's [`Transform`] as a mutable [`Vec2`],
+/// leaving z untouched. The read-only form is [`Translation2d`].
+///
+/// The item is a [`Translation2dView`], which dereferences to a `Vec2`. Changes are written
+/// back to the `Transform` when the view is dropped, and only mark it as changed if the x or y
+/// actually differ.
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(mut query: Query<Translation2dMut>) {
+///     let mut xy = query.get_single_mut().unwrap();
+///     *xy += Vec2::X;
+///     xy.y = 0.0;
+///     drop(xy);
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let id = world.spawn(Transform::from_xyz(1.0, 2.0, 3.0)).id();
+/// # let mut query = world.query::<Translation2dMut>();
+/// # let mut xy = query.single_mut(&mut world);
+/// # *xy = Vec2::new(4.0, 5.0);
+/// # xy.x += 1.0;
+/// # drop(xy);
+/// # assert_eq!(world.get::<Transform>(id).unwrap().translation, Vec3::new(5.0, 5.0, 3.0));
+/// ```
+/// ## Counter Example: The view must be dropped before the query is read again
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(mut query: Query<Translation2dMut>) {
+///     let mut xy = query.get_single_mut().unwrap();
+///     *xy += Vec2::X;
+///     let _: Vec2 = query.get_single().unwrap();
+///     xy.y = 0.0;
+/// }
+/// ```
+pub type Translation2dMut = ModQMut<Translation2dMutQ>;
+impl ModQueryMut for Translation2dMutQ {
+    type FromQuery = &'static mut Transform;
+    type ModItem<'a> = Translation2dView<'a>;
+    type ReadOnly = Translation2d;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        Translation2dView {
+            xy: t.translation.truncate(),
+            transform: t,
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// The item of [`Translation2dMut`]: the x and y of a [`Transform`]'s translation as a [`Vec2`].
+///
+/// Changes are written back to the `Transform` when the view is dropped.
+pub struct Translation2dView<'a> {
+    xy: Vec2,
+    transform: Mut<'a, Transform>,
+}
+
+impl Deref for Translation2dView<'_> {
+    type Target = Vec2;
+
+    fn deref(&self) -> &Vec2 {
+        &self.xy
+    }
+}
+
+impl DerefMut for Translation2dView<'_> {
+    fn deref_mut(&mut self) -> &mut Vec2 {
+        &mut self.xy
+    }
+}
+
+impl Drop for Translation2dView<'_> {
+    fn drop(&mut self) {
+        if self.transform.translation.truncate() != self.xy {
+            self.transform.translation.x = self.xy.x;
+            self.transform.translation.y = self.xy.y;
+        }
+    }
+}
+
+impl Debug for Translation2dView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Translation2dView").field(&self.xy).finish()
+    }
+}
+
+/// Returns the rotation of an entity's [`Transform`], or of another [`TransformLike`] component
+/// such as [`GlobalTransform`]
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<RotationOf>) {
+///     let _: Quat = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<RotationOf<GlobalTransform>>) {
+///     let _: Quat = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let rotation = Quat::from_rotation_z(1.0);
+/// # world.spawn((Transform::from_rotation(rotation), GlobalTransform::from_rotation(rotation)));
+/// # let mut query = world.query::<RotationOf>();
+/// # assert_eq!(query.single(&world), rotation);
+/// # let mut query = world.query::<RotationOf<GlobalTransform>>();
+/// # assert!(query.single(&world).abs_diff_eq(rotation, 1e-6));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Facing(Quat);
+///
+/// fn bad_example(query: Query<RotationOf<Facing>>) {
+///     let _: Quat = query.get_single().unwrap();
+/// }
+/// ```
+pub type RotationOf<T = Transform> = ModQ<RotationOfQ<T>>;
+impl<T: Component + TransformLike> ModQuery for RotationOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Quat;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::rotation(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the scale of an entity's [`Transform`], or of another [`TransformLike`] component
+/// such as [`GlobalTransform`]
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ScaleOf>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<ScaleOf<GlobalTransform>>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let scale = Vec3::new(1.0, 2.0, 3.0);
+/// # world.spawn((Transform::from_scale(scale), GlobalTransform::from_scale(scale)));
+/// # let mut query = world.query::<ScaleOf>();
+/// # assert_eq!(query.single(&world), scale);
+/// # let mut query = world.query::<ScaleOf<GlobalTransform>>();
+/// # assert!(query.single(&world).abs_diff_eq(scale, 1e-6));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Size(Vec3);
+///
+/// fn bad_example(query: Query<ScaleOf<Size>>) {
+///     let _: Vec3 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ScaleOf<T = Transform> = ModQ<ScaleOfQ<T>>;
+impl<T: Component + TransformLike> ModQuery for ScaleOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Vec3;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::scale(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the unit forward (local -Z) direction of an entity's [`Transform`], or of another
+/// [`TransformLike`] component such as [`GlobalTransform`]
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ForwardOf>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<ForwardOf<GlobalTransform>>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+/// # world.spawn(Transform::from_rotation(rotation));
+/// # let mut query = world.query::<ForwardOf>();
+/// # assert!(query.single(&world).abs_diff_eq(rotation * Vec3::NEG_Z, 1e-6));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Facing(Quat);
+///
+/// fn bad_example(query: Query<ForwardOf<Facing>>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+/// ```
+pub type ForwardOf<T = Transform> = ModQ<ForwardOfQ<T>>;
+impl<T: Component + TransformLike> ModQuery for ForwardOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Dir3;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::forward(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the unit right (local +X) direction of an entity's [`Transform`], or of another
+/// [`TransformLike`] component such as [`GlobalTransform`]
+///
+/// Requires the `bevy_transform` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<RightOf>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+///
+/// fn global_example(query: Query<RightOf<GlobalTransform>>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
+/// # world.spawn(Transform::from_rotation(rotation));
+/// # let mut query = world.query::<RightOf>();
+/// # assert!(query.single(&world).abs_diff_eq(rotation * Vec3::X, 1e-6));
+/// ```
+/// ## Counter Example: Component must be a transform
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// #[derive(Component)]
+/// struct Facing(Quat);
+///
+/// fn bad_example(query: Query<RightOf<Facing>>) {
+///     let _: Dir3 = query.get_single().unwrap();
+/// }
+/// ```
+pub type RightOf<T = Transform> = ModQ<RightOfQ<T>>;
+impl<T: Component + TransformLike> ModQuery for RightOfQ<T> {
+    type FromQuery = &'static T;
+    type ModItem<'a> = Dir3;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        TransformLike::right(t)
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
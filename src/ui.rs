@@ -0,0 +1,137 @@
+use bevy::ecs::query::WorldQuery;
+use bevy::math::Vec2;
+use bevy::ui::{ComputedNode, Interaction};
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct IsHoveredFlagQ;
+#[derive(Debug)]
+pub struct IsPressedFlagQ;
+#[derive(Debug)]
+pub struct ComputedNodeSizeOfQ;
+
+/// Returns whether the cursor is over a UI node, according to its [`Interaction`]
+///
+/// A pressed node is also considered hovered. Use [`AsFilter<IsHoveredFlag>`](crate::AsFilter)
+/// to only iterate hovered nodes. Requires the `bevy_ui` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<IsHoveredFlag, Changed<Interaction>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// fn highlight_hovered(query: Query<Entity, AsFilter<IsHoveredFlag>>) {
+///     for _hovered in query.iter() {}
+/// }
+///
+/// # let mut world = World::new();
+/// # let pressed = world.spawn(Interaction::Pressed).id();
+/// # world.spawn(Interaction::None);
+/// # let mut query = world.query::<IsHoveredFlag>();
+/// # assert!(query.get(&world, pressed).unwrap());
+/// # let mut query = world.query_filtered::<Entity, AsFilter<IsHoveredFlag>>();
+/// # assert_eq!(query.iter(&world).collect::<Vec<_>>(), [pressed]);
+/// ```
+/// ## Counter Example: IsHoveredFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, IsHoveredFlag>) {
+///     for _hovered in query.iter() {}
+/// }
+/// ```
+pub type IsHoveredFlag = ModQ<IsHoveredFlagQ>;
+impl ModQuery for IsHoveredFlagQ {
+    type FromQuery = &'static Interaction;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        *t != Interaction::None
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether a UI node is being pressed, according to its [`Interaction`]
+///
+/// Use [`AsFilter<IsPressedFlag>`](crate::AsFilter) to only iterate pressed nodes, such as for
+/// button handlers. Requires the `bevy_ui` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<IsPressedFlag, Changed<Interaction>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// fn on_press(query: Query<Entity, (Changed<Interaction>, AsFilter<IsPressedFlag>)>) {
+///     for _pressed in query.iter() {}
+/// }
+///
+/// # let mut world = World::new();
+/// # let hovered = world.spawn(Interaction::Hovered).id();
+/// # let pressed = world.spawn(Interaction::Pressed).id();
+/// # let mut query = world.query::<IsPressedFlag>();
+/// # assert!(!query.get(&world, hovered).unwrap());
+/// # let mut query = world.query_filtered::<Entity, AsFilter<IsPressedFlag>>();
+/// # assert_eq!(query.iter(&world).collect::<Vec<_>>(), [pressed]);
+/// ```
+/// ## Counter Example: IsPressedFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, IsPressedFlag>) {
+///     for _pressed in query.iter() {}
+/// }
+/// ```
+pub type IsPressedFlag = ModQ<IsPressedFlagQ>;
+impl ModQuery for IsPressedFlagQ {
+    type FromQuery = &'static Interaction;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        *t == Interaction::Pressed
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns the size of a UI node in physical pixels, according to its [`ComputedNode`]
+///
+/// Requires the `bevy_ui` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ComputedNodeSizeOf, Changed<ComputedNode>>) {
+///     let _: Vec2 = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(ComputedNode::default());
+/// # let mut query = world.query::<ComputedNodeSizeOf>();
+/// # assert_eq!(query.single(&world), Vec2::ZERO);
+/// ```
+pub type ComputedNodeSizeOf = ModQ<ComputedNodeSizeOfQ>;
+impl ModQuery for ComputedNodeSizeOfQ {
+    type FromQuery = &'static ComputedNode;
+    type ModItem<'a> = Vec2;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.size()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
@@ -0,0 +1,153 @@
+use bevy::ecs::query::WorldQuery;
+use bevy::render::view::{InheritedVisibility, RenderLayers, ViewVisibility};
+
+use super::base::{ModQ, ModQuery};
+
+#[derive(Debug)]
+pub struct IsVisibleFlagQ;
+#[derive(Debug)]
+pub struct ViewVisibilityFlagQ;
+#[derive(Debug)]
+pub struct RenderLayersContainsQ<const L: usize>;
+
+/// Returns whether an entity is effectively visible, taking the [`Visibility`] of its ancestors
+/// into account
+///
+/// This reads [`InheritedVisibility`], which bevy computes from `Visibility` and the hierarchy
+/// each frame, so gameplay systems don't need to know which visibility component is
+/// authoritative. Use [`AsFilter<IsVisibleFlag>`](crate::AsFilter) to only iterate visible
+/// entities. Requires the `bevy_render` feature.
+///
+/// [`Visibility`]: bevy::render::view::Visibility
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<IsVisibleFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(InheritedVisibility::VISIBLE);
+/// # let mut query = world.query::<IsVisibleFlag>();
+/// # assert!(query.single(&world));
+/// ```
+/// ## Counter Example: IsVisibleFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, IsVisibleFlag>) {
+///     for _visible in query.iter() {}
+/// }
+/// ```
+pub type IsVisibleFlag = ModQ<IsVisibleFlagQ>;
+impl ModQuery for IsVisibleFlagQ {
+    type FromQuery = &'static InheritedVisibility;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.get()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity was visible in any view during the last frame, according to its
+/// [`ViewVisibility`]
+///
+/// Unlike [`IsVisibleFlag`], this accounts for culling, so it's useful for skipping LOD or audio
+/// logic for off-screen entities that still need to be iterated. Use
+/// [`AsFilter<ViewVisibilityFlag>`](crate::AsFilter) to only iterate on-screen entities. Requires
+/// the `bevy_render` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn example(query: Query<ViewVisibilityFlag>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # world.spawn(ViewVisibility::HIDDEN);
+/// # let mut query = world.query::<ViewVisibilityFlag>();
+/// # assert!(!query.single(&world));
+/// ```
+/// ## Counter Example: ViewVisibilityFlag is data, so filtering needs AsFilter
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<Entity, ViewVisibilityFlag>) {
+///     for _on_screen in query.iter() {}
+/// }
+/// ```
+pub type ViewVisibilityFlag = ModQ<ViewVisibilityFlagQ>;
+impl ModQuery for ViewVisibilityFlagQ {
+    type FromQuery = &'static ViewVisibility;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        t.get()
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}
+
+/// Returns whether an entity's [`RenderLayers`] include layer `L`
+///
+/// Entities without `RenderLayers` are treated as being on the default layer, layer 0. Requires
+/// the `bevy_render` feature.
+///
+/// ## Example
+/// ```
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// # use bevy::render::view::RenderLayers;
+/// fn example(query: Query<RenderLayersContains<1>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+///
+/// # let mut world = World::new();
+/// # let minimap = world.spawn(RenderLayers::from_layers(&[1, 70])).id();
+/// # let default = world.spawn_empty().id();
+/// # let mut query = world.query::<RenderLayersContains<1>>();
+/// # assert!(query.get(&world, minimap).unwrap());
+/// # assert!(!query.get(&world, default).unwrap());
+/// # let mut query = world.query::<RenderLayersContains<70>>();
+/// # assert!(query.get(&world, minimap).unwrap());
+/// # let mut query = world.query::<RenderLayersContains<0>>();
+/// # assert!(!query.get(&world, minimap).unwrap());
+/// # assert!(query.get(&world, default).unwrap());
+/// ```
+/// ## Counter Example: The layer must be a constant
+/// ```compile_fail
+/// # use bevy_query_ext::prelude::*;
+/// # use bevy::prelude::*;
+/// fn bad_example(query: Query<RenderLayersContains<-1>>) {
+///     let _: bool = query.get_single().unwrap();
+/// }
+/// ```
+pub type RenderLayersContains<const L: usize> = ModQ<RenderLayersContainsQ<L>>;
+impl<const L: usize> ModQuery for RenderLayersContainsQ<L> {
+    type FromQuery = Option<&'static RenderLayers>;
+    type ModItem<'a> = bool;
+
+    fn modify_reference(t: <Self::FromQuery as WorldQuery>::Item<'_>) -> Self::ModItem<'_> {
+        match t {
+            Some(layers) => layers
+                .bits()
+                .get(L / 64)
+                .is_some_and(|word| word & (1 << (L % 64)) != 0),
+            None => L == 0,
+        }
+    }
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::ModItem<'wlong>) -> Self::ModItem<'wshort> {
+        item
+    }
+}